@@ -11,7 +11,18 @@ impl Interval {
     #[must_use]
     pub fn abs(self) -> Self {
 		let (a, b) = (self.inf, self.sup);
-		
+
+        // Fast paths for intervals that lie entirely on one side of zero.
+        // The empty interval fails both comparisons and falls through to `classify`.
+        if a >= 0.0 {
+            // P0 | P1 | Z
+            return self;
+        }
+        if b < 0.0 {
+            // N1 => [-b, -a]
+            return Self { inf: -b, sup: -a };
+        }
+
         use IntervalClass::*;
         match self.classify() {
             E | P0 | P1 | Z => self,
@@ -26,6 +37,26 @@ impl Interval {
         }
     }
 
+    /// Returns the range of the absolute value over `self`, which is $\[\operatorname{mig}(\self), \operatorname{mag}(\self)\]$
+    /// if `self` is nonempty; otherwise, $∅$.
+    ///
+    /// This is the same as [`Interval::abs`]. The name is provided to make it explicit that
+    /// the result is an interval, whereas [`Interval::mig`] and [`Interval::mag`] return
+    /// its bounds as scalars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(-3.0, 2.0);
+    /// assert_eq!(x.abs_range(), const_interval!(0.0, 3.0));
+    /// assert_eq!(x.abs_range(), interval!(x.mig(), x.mag()).unwrap());
+    /// ```
+    #[must_use]
+    pub fn abs_range(self) -> Self {
+        self.abs()
+    }
+
     /// Returns the maximum of `self` and `rhs`.
     ///
     /// The domain and the range of the point function are:
@@ -57,6 +88,54 @@ impl Interval {
 
 		Self { inf: f64::min(self.inf, rhs.inf), sup: f64::min(self.sup, rhs.sup) }
     }
+
+    /// Returns the maximum of the members of `xs`, skipping empty intervals.
+    ///
+    /// Unlike [`Interval::max`], an empty member does not make the result empty.
+    /// The result is $∅$ only if `xs` has no nonempty members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let xs = [const_interval!(1.0, 4.0), Interval::EMPTY, const_interval!(2.0, 3.0)];
+    /// assert_eq!(Interval::max_all(&xs), const_interval!(2.0, 4.0));
+    /// assert_eq!(Interval::max_all(&[]), Interval::EMPTY);
+    /// ```
+    ///
+    /// See also: [`Interval::min_all`].
+    #[must_use]
+    pub fn max_all(xs: &[Interval]) -> Self {
+        xs.iter()
+            .filter(|x| !x.is_empty())
+            .copied()
+            .reduce(Self::max)
+            .unwrap_or(Self::EMPTY)
+    }
+
+    /// Returns the minimum of the members of `xs`, skipping empty intervals.
+    ///
+    /// Unlike [`Interval::min`], an empty member does not make the result empty.
+    /// The result is $∅$ only if `xs` has no nonempty members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let xs = [const_interval!(1.0, 4.0), Interval::EMPTY, const_interval!(2.0, 3.0)];
+    /// assert_eq!(Interval::min_all(&xs), const_interval!(1.0, 3.0));
+    /// assert_eq!(Interval::min_all(&[]), Interval::EMPTY);
+    /// ```
+    ///
+    /// See also: [`Interval::max_all`].
+    #[must_use]
+    pub fn min_all(xs: &[Interval]) -> Self {
+        xs.iter()
+            .filter(|x| !x.is_empty())
+            .copied()
+            .reduce(Self::min)
+            .unwrap_or(Self::EMPTY)
+    }
 }
 
 #[cfg(test)]
@@ -74,4 +153,60 @@ mod tests {
         assert!(I::EMPTY.min(I::PI).is_empty());
         assert!(I::PI.min(I::EMPTY).is_empty());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn min_max_all() {
+        let xs = [
+            const_interval!(-1.0, 2.0),
+            I::EMPTY,
+            const_interval!(0.0, 5.0),
+            const_interval!(-3.0, -2.0),
+        ];
+        assert_eq!(I::max_all(&xs), const_interval!(0.0, 5.0));
+        assert_eq!(I::min_all(&xs), const_interval!(-3.0, -2.0));
+
+        assert_eq!(I::max_all(&xs[..1]), xs[0]);
+        assert_eq!(I::min_all(&xs[..1]), xs[0]);
+        assert_eq!(I::max_all(&[I::ENTIRE, I::PI]), const_interval!(I::PI.inf, f64::INFINITY));
+        assert_eq!(I::min_all(&[I::ENTIRE, I::PI]), const_interval!(f64::NEG_INFINITY, I::PI.sup));
+
+        assert!(I::max_all(&[]).is_empty());
+        assert!(I::min_all(&[]).is_empty());
+        assert!(I::max_all(&[I::EMPTY, I::EMPTY]).is_empty());
+        assert!(I::min_all(&[I::EMPTY]).is_empty());
+    }
+
+    #[test]
+    fn abs_signed_zero() {
+        let x = const_interval!(-0.0, 1.0).abs();
+        assert!(x.inf == 0.0 && x.inf.is_sign_negative() && x.sup == 1.0);
+
+        let x = const_interval!(-1.0, -0.0).abs();
+        assert!(x.inf == 0.0 && x.inf.is_sign_positive() && x.sup == 1.0);
+
+        let x = const_interval!(-0.0, -0.0).abs();
+        assert!(x.inf.is_sign_negative() && x.sup.is_sign_negative());
+
+        assert_eq!(const_interval!(2.0, 3.0).abs(), const_interval!(2.0, 3.0));
+        assert_eq!(const_interval!(-3.0, -2.0).abs(), const_interval!(2.0, 3.0));
+        assert_eq!(const_interval!(-3.0, 2.0).abs(), const_interval!(0.0, 3.0));
+        assert_eq!(I::ENTIRE.abs(), const_interval!(0.0, f64::INFINITY));
+    }
+
+    #[test]
+    fn abs_range() {
+        for x in [
+            const_interval!(-3.0, 2.0),
+            const_interval!(1.0, 4.0),
+            const_interval!(-4.0, -1.0),
+            const_interval!(0.0, 0.0),
+            const_interval!(f64::NEG_INFINITY, -1.0),
+            I::ENTIRE,
+        ] {
+            assert_eq!(x.abs_range(), x.abs());
+            assert_eq!(x.abs_range().inf, x.mig());
+            assert_eq!(x.abs_range().sup, x.mag());
+        }
+        assert!(I::EMPTY.abs_range().is_empty());
+    }
+}
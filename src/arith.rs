@@ -1,7 +1,153 @@
 use crate::{interval::*, classify::*};
 
 use forward_ref::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+// Returns `(s, e)` such that `s` is `x + y` rounded to nearest and `s + e` is the exact sum,
+// provided that no overflow occurs (TwoSum).
+pub(crate) fn two_sum(x: f64, y: f64) -> (f64, f64) {
+    let s = x + y;
+    let y_virtual = s - x;
+    let x_virtual = s - y_virtual;
+    (s, (x - x_virtual) + (y - y_virtual))
+}
+
+// Returns `x + y` rounded toward -∞.
+// A finite sum that overflows to +∞ is rounded to MAX.
+pub(crate) fn add_down(x: f64, y: f64) -> f64 {
+    let (s, e) = two_sum(x, y);
+    if e < 0.0 {
+        s.next_down()
+    } else if s == f64::INFINITY && x.is_finite() && y.is_finite() {
+        f64::MAX
+    } else {
+        s
+    }
+}
+
+// Returns `x + y` rounded toward +∞.
+// A finite sum that overflows to -∞ is rounded to MIN.
+pub(crate) fn add_up(x: f64, y: f64) -> f64 {
+    let (s, e) = two_sum(x, y);
+    if e > 0.0 {
+        s.next_up()
+    } else if s == f64::NEG_INFINITY && x.is_finite() && y.is_finite() {
+        f64::MIN
+    } else {
+        s
+    }
+}
+
+// Returns `(p, e)` such that `p` is `x × y` rounded to nearest and `p + e` is the exact product,
+// provided that neither overflow nor underflow occurs (TwoProduct).
+fn two_prod(x: f64, y: f64) -> (f64, f64) {
+    let p = x * y;
+    (p, x.mul_add(y, -p))
+}
+
+// Returns `true` if the product `p` of nonzero `x` and `y` is in the subnormal range,
+// where the error term of `two_prod` can be lost.
+fn is_tiny_product(x: f64, y: f64, p: f64) -> bool {
+    p.abs() < f64::MIN_POSITIVE && x != 0.0 && y != 0.0
+}
+
+// Returns `x × y` rounded toward -∞, or the number next below it.
+// A finite product that overflows to +∞ is rounded to MAX.
+pub(crate) fn mul_down(x: f64, y: f64) -> f64 {
+    let (p, e) = two_prod(x, y);
+    if p == f64::INFINITY && x.is_finite() && y.is_finite() {
+        f64::MAX
+    } else if e < 0.0 || e == 0.0 && is_tiny_product(x, y, p) {
+        p.next_down()
+    } else {
+        p
+    }
+}
+
+// Returns `x × y` rounded toward +∞, or the number next above it.
+// A finite product that overflows to -∞ is rounded to MIN.
+pub(crate) fn mul_up(x: f64, y: f64) -> f64 {
+    let (p, e) = two_prod(x, y);
+    if p == f64::NEG_INFINITY && x.is_finite() && y.is_finite() {
+        f64::MIN
+    } else if e > 0.0 || e == 0.0 && is_tiny_product(x, y, p) {
+        p.next_up()
+    } else {
+        p
+    }
+}
+
+// Returns `(q, e)` such that `q` is `x / y` rounded to nearest and `e` has the sign of
+// the error of `q`, that is, of `x / y - q`, provided that neither overflow nor underflow occurs.
+fn div_with_error_sign(x: f64, y: f64) -> (f64, f64) {
+    let q = x / y;
+    // x - q × y is exact.
+    let r = (-q).mul_add(y, x);
+    (q, if y > 0.0 { r } else { -r })
+}
+
+// Returns `true` if the quotient `q` of nonzero `x` and finite `y` is in the subnormal range,
+// where the error sign of `div_with_error_sign` can be lost.
+fn is_tiny_quotient(x: f64, y: f64, q: f64) -> bool {
+    q.abs() < f64::MIN_POSITIVE && x != 0.0 && y.is_finite()
+}
+
+// Returns `x / y` rounded toward -∞, or the number next below it.
+// A finite quotient that overflows to +∞ is rounded to MAX.
+pub(crate) fn div_down(x: f64, y: f64) -> f64 {
+    let (q, e) = div_with_error_sign(x, y);
+    if q == f64::INFINITY && x.is_finite() && y != 0.0 {
+        f64::MAX
+    } else if e < 0.0 || e == 0.0 && is_tiny_quotient(x, y, q) {
+        q.next_down()
+    } else {
+        q
+    }
+}
+
+// Returns `x / y` rounded toward +∞, or the number next above it.
+// A finite quotient that overflows to -∞ is rounded to MIN.
+pub(crate) fn div_up(x: f64, y: f64) -> f64 {
+    let (q, e) = div_with_error_sign(x, y);
+    if q == f64::NEG_INFINITY && x.is_finite() && y != 0.0 {
+        f64::MIN
+    } else if e > 0.0 || e == 0.0 && is_tiny_quotient(x, y, q) {
+        q.next_up()
+    } else {
+        q
+    }
+}
+
+// Returns `true` if `x × y + z` is exactly representable, which is sufficient but not necessary.
+// A tiny product is never regarded as exact, since the error terms can underflow to zero.
+fn mul_add_is_exact(x: f64, y: f64, z: f64) -> bool {
+    let (p, e) = two_prod(x, y);
+    let (_, e1) = two_sum(p, z);
+    e == 0.0 && e1 == 0.0 && !is_tiny_product(x, y, p)
+}
+
+// Returns `x × y + z` rounded toward -∞.
+fn mul_add_down(x: f64, y: f64, z: f64) -> f64 {
+    let r = x.mul_add(y, z);
+    if mul_add_is_exact(x, y, z) {
+        r
+    } else {
+        r.next_down()
+    }
+}
+
+// Returns `x × y + z` rounded toward +∞.
+fn mul_add_up(x: f64, y: f64, z: f64) -> f64 {
+    let r = x.mul_add(y, z);
+    if mul_add_is_exact(x, y, z) {
+        r
+    } else {
+        r.next_up()
+    }
+}
 
 impl Neg for Interval {
     type Output = Self;
@@ -22,11 +168,11 @@ impl Add for Interval {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self {
-        // [a + c, b + d] = [-a - c; b + d] = [-a; b] .+ [-c; d]
-        Self { 
-			inf: self.inf + rhs.inf,
-			sup: self.sup + rhs.sup,
-		}
+        // [a + c, b + d], rounded outward.
+        Self {
+            inf: add_down(self.inf, rhs.inf),
+            sup: add_up(self.sup, rhs.sup),
+        }
     }
 }
 
@@ -37,11 +183,11 @@ impl Sub for Interval {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self {
-        // [a - d, b - c]
-        Self { 
-			inf: self.inf - rhs.sup,
-			sup: self.sup - rhs.inf,
-		}
+        // [a - d, b - c], rounded outward.
+        Self {
+            inf: add_down(self.inf, -rhs.sup),
+            sup: add_up(self.sup, -rhs.inf),
+        }
     }
 }
 
@@ -62,6 +208,9 @@ impl Mul for Interval {
         //  P | [b*c, b*d] | [b*c, a*d] | [a*c, b*d] | {0}
         //  Z |     {0}    |     {0}    |     {0}    | {0}
         // *1 [min{a*d, b*c}, max{a*c, b*d}]
+        //
+        // The products are rounded outward by `mul_down` and `mul_up`. This costs at most
+        // one ulp of width per bound compared to rounding to nearest, and nothing for exact products.
 
 		let (a, b) = (self.inf, self.sup);
 		let (c, d) = (rhs.inf, rhs.sup);
@@ -74,39 +223,39 @@ impl Mul for Interval {
             M_Z | N0_Z | N1_Z | P0_Z | P1_Z | Z_M | Z_N0 | Z_N1 | Z_P0 | Z_P1 | Z_Z => Self::zero(),
             M_M => {
                 // M * M => [min(a*d, b*c), max(a*c, b*d)]
-                Self { inf: f64::min(a * d, b * c), sup: f64::max(a * c, b * d) }
+                Self { inf: f64::min(mul_down(a, d), mul_down(b, c)), sup: f64::max(mul_up(a, c), mul_up(b, d)) }
             }
             M_N0 | M_N1 => {
                 // M * N => [b*c, a*c]
-                Self { inf: b * c, sup: a * c }
+                Self { inf: mul_down(b, c), sup: mul_up(a, c) }
             }
             M_P0 | M_P1 => {
                 // M * P => [a*d, b*d]
-                Self { inf: a * d, sup: b * d }
+                Self { inf: mul_down(a, d), sup: mul_up(b, d) }
             }
             N0_M | N1_M => {
                 // N * M => [a*d, a*c]
-                Self { inf: a * d, sup: a * c }
+                Self { inf: mul_down(a, d), sup: mul_up(a, c) }
             }
             N0_N0 | N0_N1 | N1_N0 | N1_N1 => {
                 // N * N => [b*d, a*c]
-                Self { inf: b * d, sup: a * c }
+                Self { inf: mul_down(b, d), sup: mul_up(a, c) }
             }
             N0_P0 | N0_P1 | N1_P0 | N1_P1 => {
                 // N * P => [a*d, b*c]
-                Self { inf: a * d, sup: b * c }
+                Self { inf: mul_down(a, d), sup: mul_up(b, c) }
             }
             P0_M | P1_M => {
                 // P * M => [b*c, b*d]
-                Self { inf: b * c, sup: b * d }
+                Self { inf: mul_down(b, c), sup: mul_up(b, d) }
             }
             P0_N0 | P0_N1 | P1_N0 | P1_N1 => {
                 // P * N => [b*c, a*d]
-                Self { inf: b * c, sup: a * d }
+                Self { inf: mul_down(b, c), sup: mul_up(a, d) }
             }
             P0_P0 | P0_P1 | P1_P0 | P1_P1 => {
                 // P * P => [a*c, b*d]
-                Self { inf: a * c, sup: b * d }
+                Self { inf: mul_down(a, c), sup: mul_up(b, d) }
             }
         }
     }
@@ -127,6 +276,8 @@ impl Div for Interval {
         //  N |  ℝ  | [b/c, +∞] | [b/c, a/d] | [-∞, b/d] | [a/c, b/d] | ∅
         //  P |  ℝ  | [-∞, a/c] | [b/d, a/c] | [a/d, +∞] | [a/d, b/c] | ∅
         //  Z | {0} |    {0}    |     {0}    |    {0}    |     {0}    | ∅
+        //
+        // The finite quotients are rounded outward by `div_down` and `div_up`.
 
 
 		let (a, b) = (self.inf, self.sup);
@@ -140,43 +291,43 @@ impl Div for Interval {
             Z_M | Z_N0 | Z_N1 | Z_P0 | Z_P1 => Self::zero(),
             M_N1 => {
                 // M / N1 => [b/d, a/d]
-                Self { inf: b / d, sup: a / d }
+                Self { inf: div_down(b, d), sup: div_up(a, d) }
             }
             M_P1 => {
                 // M / P1 => [a/c, b/c]
-                Self { inf: a / c, sup: b / c }
+                Self { inf: div_down(a, c), sup: div_up(b, c) }
             }
             N0_N0 | N1_N0 => {
                 // N / N0 => [b/c, +∞]
-				Self { inf: b / c, sup: f64::INFINITY }
+				Self { inf: div_down(b, c), sup: f64::INFINITY }
             }
             N0_N1 | N1_N1 => {
                 // N / N1 => [b/c, a/d]
-				Self { inf: b / c, sup: a / d }
+				Self { inf: div_down(b, c), sup: div_up(a, d) }
             }
             N0_P0 | N1_P0 => {
                 // N / P0 => [-∞, b/d] 
-				Self { inf: f64::NEG_INFINITY, sup: b / d }
+				Self { inf: f64::NEG_INFINITY, sup: div_up(b, d) }
             }
             N0_P1 | N1_P1 => {
                 // N / P1 => [a/c, b/d]
-				Self { inf: a / c, sup: b / d }
+				Self { inf: div_down(a, c), sup: div_up(b, d) }
             }
             P0_N0 | P1_N0 => {
                 // P / N0 => [-∞, a/c]
-				Self { inf: f64::NEG_INFINITY, sup: a / c }
+				Self { inf: f64::NEG_INFINITY, sup: div_up(a, c) }
             }
             P0_N1 | P1_N1 => {
                 // P / N1 => [b/d, a/c] 
-				Self { inf: b / d, sup: a / c }
+				Self { inf: div_down(b, d), sup: div_up(a, c) }
             }
             P0_P0 | P1_P0 => {
                 // P / P0 => [a/d, +∞]
-				Self { inf: a / d, sup: f64::INFINITY }
+				Self { inf: div_down(a, d), sup: f64::INFINITY }
             }
             P0_P1 | P1_P1 => {
                 // P / P1 => [a/d, b/c]
-				Self { inf: a / d, sup: b / c }
+				Self { inf: div_down(a, d), sup: div_up(b, c) }
             }
         }
     }
@@ -184,6 +335,84 @@ impl Div for Interval {
 
 forward_ref_binop!(impl Div, div for Interval, Interval);
 
+impl Interval {
+    /// Returns the reciprocal of `self`.
+    ///
+    /// The result is the same as `const_interval!(1.0, 1.0) / self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain        | Range         |
+    /// | ------------- | ------------- |
+    /// | $\R ∖ \set 0$ | $\R ∖ \set 0$ |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(2.0, 4.0).recip(), const_interval!(0.25, 0.5));
+    /// assert_eq!(const_interval!(0.0, 4.0).recip(), const_interval!(0.25, f64::INFINITY));
+    /// assert_eq!(const_interval!(-2.0, 2.0).recip(), Interval::ENTIRE);
+    /// assert_eq!(Interval::EMPTY.recip(), Interval::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn recip(self) -> Self {
+        // 1 / [a, b] =
+        //
+        //  M  |     N0     |     N1     |     P0    |     P1     | Z
+        // ----+------------+------------+-----------+------------+---
+        //  ℝ  | [-∞, 1/a]  | [1/b, 1/a] | [1/b, +∞] | [1/b, 1/a] | ∅
+
+        let (a, b) = (self.inf, self.sup);
+
+        use IntervalClass::*;
+        match self.classify() {
+            E | Z => Self::EMPTY,
+            M => Self::ENTIRE,
+            N0 => Self { inf: f64::NEG_INFINITY, sup: div_up(1.0, a) },
+            P0 => Self { inf: div_down(1.0, b), sup: f64::INFINITY },
+            N1 | P1 => Self { inf: div_down(1.0, b), sup: div_up(1.0, a) },
+        }
+    }
+
+    /// Returns $\self × a + b$ with fused multiply-add, for evaluating polynomials by Horner's scheme.
+    ///
+    /// The result is an enclosure of $\set{x y + z ∣ x ∈ \self, y ∈ a, z ∈ b}$ as is `self * a + b`.
+    /// If all of the intervals are bounded, each bound of the result is computed by [`f64::mul_add`]
+    /// from the bounds of the operands, so it is rounded outward only once instead of twice.
+    /// Otherwise, the result is the same as `self * a + b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(1.0, 2.0);
+    /// assert_eq!(x.mul_add(const_interval!(3.0), const_interval!(-1.0, 1.0)), const_interval!(2.0, 7.0));
+    /// // The product 0.1 × 10 is not rounded to 1 before the addition.
+    /// let x = const_interval!(0.1);
+    /// let y = x.mul_add(const_interval!(10.0), const_interval!(-1.0));
+    /// assert!(y.inf > 0.0);
+    /// assert!(y.subset(x * const_interval!(10.0) + const_interval!(-1.0)));
+    /// ```
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        if !(self.is_common_interval() && a.is_common_interval() && b.is_common_interval()) {
+            return self * a + b;
+        }
+
+        // The bounds of the product are attained at the corners.
+        let xs = [self.inf, self.inf, self.sup, self.sup];
+        let ys = [a.inf, a.sup, a.inf, a.sup];
+        let mut inf = f64::INFINITY;
+        let mut sup = f64::NEG_INFINITY;
+        for (x, y) in xs.into_iter().zip(ys) {
+            inf = inf.min(mul_add_down(x, y, b.inf));
+            sup = sup.max(mul_add_up(x, y, b.sup));
+        }
+        Self { inf, sup }
+    }
+}
+
 macro_rules! impl_op_assign {
     ($OpAssign:ident, $op_assign:ident, $op:ident) => {
         impl $OpAssign for Interval {
@@ -201,9 +430,61 @@ impl_op_assign!(SubAssign, sub_assign, sub);
 impl_op_assign!(MulAssign, mul_assign, mul);
 impl_op_assign!(DivAssign, div_assign, div);
 
+// An `f64` operand `s` is treated as the singleton [s, s].
+// A NaN or infinite `s` does not form an interval, so it is treated as ∅.
+macro_rules! impl_scalar_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl $Op<f64> for Interval {
+            type Output = Self;
+
+            fn $op(self, rhs: f64) -> Self {
+                self.$op(Self::from_bounds_lossy(rhs, rhs))
+            }
+        }
+
+        forward_ref_binop!(impl $Op, $op for Interval, f64);
+
+        impl $Op<Interval> for f64 {
+            type Output = Interval;
+
+            fn $op(self, rhs: Interval) -> Interval {
+                Interval::from_bounds_lossy(self, self).$op(rhs)
+            }
+        }
+
+        forward_ref_binop!(impl $Op, $op for f64, Interval);
+
+        impl $OpAssign<f64> for Interval {
+            fn $op_assign(&mut self, rhs: f64) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        forward_ref_op_assign!(impl $OpAssign, $op_assign for Interval, f64);
+    };
+}
+
+impl_scalar_op!(Add, add, AddAssign, add_assign);
+impl_scalar_op!(Sub, sub, SubAssign, sub_assign);
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign);
+impl_scalar_op!(Div, div, DivAssign, div_assign);
+
+impl Sum for Interval {
+    /// Returns the sum of the intervals, which is $\[0, 0\]$ for an empty iterator.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Interval> for Interval {
+    fn sum<I: Iterator<Item = &'a Interval>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use crate::{test_util::sample_f64s, *};
     use Interval as I;
 
     #[test]
@@ -234,6 +515,138 @@ mod tests {
         assert_eq!(i, const_interval!(1.5, 4.0));
     }
 
+    #[test]
+    fn add_sub_outward() {
+        let x = const_interval!(0.1) + const_interval!(0.2);
+        assert!(x.contains(0.3));
+        // The sum rounded to nearest is above the exact sum.
+        assert_eq!(x, interval!((0.1_f64 + 0.2).next_down(), 0.1 + 0.2).unwrap());
+
+        // 1 + ε/4 is not representable.
+        let x = const_interval!(1.0) + const_interval!(f64::EPSILON / 4.0);
+        assert_eq!(x, interval!(1.0, 1.0 + f64::EPSILON).unwrap());
+        let x = const_interval!(1.0) - const_interval!(f64::EPSILON / 4.0);
+        assert_eq!(x, interval!(1.0 - f64::EPSILON / 2.0, 1.0).unwrap());
+
+        // No widening for exact results.
+        assert_eq!(const_interval!(1.0, 2.0) + const_interval!(0.5, 0.25e1), const_interval!(1.5, 4.5));
+        assert_eq!(const_interval!(1.0, 2.0) - const_interval!(0.5, 0.25e1), const_interval!(-1.5, 1.5));
+        assert_eq!(const_interval!(0.1) - const_interval!(0.1), const_interval!(0.0));
+
+        // Overflow of finite bounds.
+        const MAX: f64 = f64::MAX;
+        assert_eq!(const_interval!(MAX) + const_interval!(MAX), interval!(MAX, f64::INFINITY).unwrap());
+        assert_eq!(const_interval!(-MAX) - const_interval!(MAX), interval!(f64::NEG_INFINITY, -MAX).unwrap());
+        assert_eq!(I::ENTIRE + const_interval!(1.0), I::ENTIRE);
+        assert_eq!(I::ENTIRE - I::ENTIRE, I::ENTIRE);
+    }
+
+    #[test]
+    fn mul_outward() {
+        // (1 + ε)(1 - ε/2) = 1 + ε/2 - ε²/2 is not representable, and is rounded to nearest to 1.
+        const X: I = const_interval!(1.0 + f64::EPSILON);
+        const Y: I = const_interval!(1.0 - f64::EPSILON / 2.0);
+        let z = X * Y;
+        assert_eq!(z, interval!(1.0, 1.0 + f64::EPSILON).unwrap());
+        assert_eq!(-X * Y, interval!(-1.0 - f64::EPSILON, -1.0).unwrap());
+
+        // 0.1 × 3 is below 0.30000000000000004, to which it is rounded to nearest.
+        let z = const_interval!(0.1) * const_interval!(3.0);
+        assert_eq!(z, interval!((0.1_f64 * 3.0).next_down(), 0.1 * 3.0).unwrap());
+        assert!(z.contains(0.1 * 3.0));
+
+        // Every class, with inexact products of the bounds.
+        let xs = [
+            const_interval!(-0.3, 0.7),
+            const_interval!(-0.7, -0.3),
+            const_interval!(0.3, 0.7),
+            const_interval!(-0.7, 0.0),
+            const_interval!(0.0, 0.3),
+        ];
+        for x in xs {
+            for y in xs {
+                let z = x * y;
+                for (s, t) in [(x.inf, y.inf), (x.inf, y.sup), (x.sup, y.inf), (x.sup, y.sup)] {
+                    let (p, e) = (s * t, s.mul_add(t, -s * t));
+                    // p + e is the exact product.
+                    assert!(z.inf < p || z.inf == p && e >= 0.0, "{} * {} = {}", x, y, z);
+                    assert!(p < z.sup || p == z.sup && e <= 0.0, "{} * {} = {}", x, y, z);
+                }
+                assert!(z.wid() <= (x.mag() * y.mag()) * 4.0, "{} * {} = {}", x, y, z);
+            }
+        }
+
+        // No widening for exact products.
+        assert_eq!(const_interval!(-1.5, 2.0) * const_interval!(-4.0, 0.25), const_interval!(-8.0, 6.0));
+        assert_eq!(const_interval!(0.1) * const_interval!(1.0), const_interval!(0.1));
+        assert_eq!(const_interval!(0.1) * const_interval!(2.0), const_interval!(0.2));
+
+        // Overflow and underflow of finite bounds.
+        const MAX: f64 = f64::MAX;
+        assert_eq!(const_interval!(MAX) * const_interval!(2.0), interval!(MAX, f64::INFINITY).unwrap());
+        assert_eq!(const_interval!(-MAX) * const_interval!(2.0), interval!(f64::NEG_INFINITY, -MAX).unwrap());
+        let z = const_interval!(1e-200) * const_interval!(1e-200);
+        assert!(z.inf <= 0.0 && 0.0 < z.sup && z.wid() <= 2.0 * f64::from_bits(1));
+        let z = const_interval!(-1e-200) * const_interval!(1e-200);
+        assert!(z.inf < 0.0 && 0.0 <= z.sup && z.wid() <= 2.0 * f64::from_bits(1));
+        assert_eq!(I::ENTIRE * const_interval!(2.0), I::ENTIRE);
+    }
+
+    #[test]
+    fn div_outward() {
+        const INF: f64 = f64::INFINITY;
+        // 1/3 rounded to nearest is below the exact 1/3.
+        let z = const_interval!(1.0) / const_interval!(3.0);
+        assert_eq!(z, interval!(1.0 / 3.0, (1.0_f64 / 3.0).next_up()).unwrap());
+        assert!(3.0_f64.mul_add(z.inf, -1.0) < 0.0 && 3.0_f64.mul_add(z.sup, -1.0) > 0.0);
+        assert_eq!(const_interval!(2.0) / const_interval!(3.0), interval!(2.0 / 3.0, (2.0_f64 / 3.0).next_up()).unwrap());
+
+        // Every class, with inexact quotients of the bounds.
+        let xs = [
+            const_interval!(-0.3, 0.7),
+            const_interval!(-0.7, -0.3),
+            const_interval!(0.3, 0.7),
+            const_interval!(-0.7, 0.0),
+            const_interval!(0.0, 0.3),
+        ];
+        for x in xs {
+            for y in xs {
+                let z = x / y;
+                for (s, t) in [(x.inf, y.inf), (x.inf, y.sup), (x.sup, y.inf), (x.sup, y.sup)] {
+                    if t == 0.0 {
+                        continue;
+                    }
+                    let q = s / t;
+                    // q - r / t is the exact quotient.
+                    let r = q.mul_add(t, -s);
+                    let below = r / t > 0.0;
+                    let above = r / t < 0.0;
+                    assert!(z.inf < q || z.inf == q && !below, "{} / {} = {}", x, y, z);
+                    assert!(q < z.sup || q == z.sup && !above, "{} / {} = {}", x, y, z);
+                }
+            }
+        }
+
+        // Unbounded results are not nudged into NaN.
+        assert_eq!(const_interval!(-0.7, -0.3) / const_interval!(-0.3, 0.0), interval!((-0.3_f64 / -0.3), INF).unwrap());
+        assert_eq!(const_interval!(0.3, 0.7) / const_interval!(0.0, 0.3), interval!(1.0, INF).unwrap());
+        let z = const_interval!(0.1, 0.7) / const_interval!(-0.3, 0.0);
+        assert_eq!(z.inf, f64::NEG_INFINITY);
+        assert!(z.contains(0.7 / -0.3) && !z.sup.is_nan());
+
+        // No widening for exact quotients.
+        assert_eq!(const_interval!(-1.5, 2.0) / const_interval!(4.0, 8.0), const_interval!(-0.375, 0.5));
+        assert_eq!(const_interval!(0.1) / const_interval!(1.0), const_interval!(0.1));
+
+        // Overflow and underflow of finite bounds.
+        const MAX: f64 = f64::MAX;
+        assert_eq!(const_interval!(MAX) / const_interval!(0.5), interval!(MAX, INF).unwrap());
+        assert_eq!(const_interval!(-MAX) / const_interval!(0.5), interval!(f64::NEG_INFINITY, -MAX).unwrap());
+        let z = const_interval!(1e-200) / const_interval!(1e200);
+        assert!(z.inf <= 0.0 && 0.0 < z.sup && z.wid() <= 2.0 * f64::from_bits(1));
+        assert_eq!(const_interval!(1.0) / const_interval!(2.0, INF), const_interval!(0.0, 0.5));
+    }
+
     #[test]
     fn empty() {
         assert!((-I::EMPTY).is_empty());
@@ -281,4 +694,183 @@ mod tests {
         e *= &E;
         e /= &E;
     }
-}
\ No newline at end of file
+
+    #[allow(clippy::op_ref)]
+    #[test]
+    fn scalar() {
+        let x = const_interval!(1.0, 2.0);
+        assert_eq!(x + 1.0, const_interval!(2.0, 3.0));
+        assert_eq!(1.0 + x, const_interval!(2.0, 3.0));
+        assert_eq!(x - 1.0, const_interval!(0.0, 1.0));
+        assert_eq!(2.0 - const_interval!(0.0, 1.0), const_interval!(1.0, 2.0));
+        assert_eq!(x * 3.0, const_interval!(3.0, 6.0));
+        assert_eq!(-3.0 * x, const_interval!(-6.0, -3.0));
+        assert_eq!(x / 2.0, const_interval!(0.5, 1.0));
+        assert_eq!(2.0 / x, const_interval!(1.0, 2.0));
+        assert_eq!(x * 0.0, const_interval!(0.0));
+        assert!((x / 0.0).is_empty());
+
+        for s in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!((x + s).is_empty());
+            assert!((s - x).is_empty());
+            assert!((x * s).is_empty());
+            assert!((s / x).is_empty());
+        }
+        assert!((I::EMPTY + 1.0).is_empty());
+        assert!((1.0 * I::EMPTY).is_empty());
+
+        assert_eq!(&x + 1.0, x + 1.0);
+        assert_eq!(x - &1.0, x - 1.0);
+        assert_eq!(&x * &3.0, x * 3.0);
+        assert_eq!(&2.0 / x, 2.0 / x);
+        assert_eq!(2.0 + &x, 2.0 + x);
+
+        let mut y = x;
+        y += 1.0;
+        y -= &0.5;
+        y *= 2.0;
+        y /= &4.0;
+        assert_eq!(y, const_interval!(0.75, 1.25));
+    }
+
+    #[test]
+    fn recip() {
+        // 1 / x for divisors on and around the zero boundary.
+        const ONE: I = const_interval!(1.0);
+        const INF: f64 = f64::INFINITY;
+        let cases = [
+            (const_interval!(2.0, 4.0), const_interval!(0.25, 0.5)),
+            (const_interval!(-4.0, -2.0), const_interval!(-0.5, -0.25)),
+            (const_interval!(0.0, 2.0), const_interval!(0.5, INF)),
+            (const_interval!(-0.0, 2.0), const_interval!(0.5, INF)),
+            (const_interval!(-2.0, 0.0), const_interval!(-INF, -0.5)),
+            (const_interval!(-2.0, -0.0), const_interval!(-INF, -0.5)),
+            (const_interval!(-1.0, 1.0), I::ENTIRE),
+            (const_interval!(0.0, INF), const_interval!(0.0, INF)),
+            (const_interval!(-INF, 0.0), const_interval!(-INF, 0.0)),
+            (const_interval!(1.0, INF), const_interval!(0.0, 1.0)),
+            (I::ENTIRE, I::ENTIRE),
+        ];
+        for (x, y) in cases {
+            assert_eq!(ONE / x, y, "1 / {}", x);
+            assert_eq!(x.recip(), y, "recip({})", x);
+        }
+
+        for x in [const_interval!(0.0), const_interval!(-0.0, 0.0), I::EMPTY] {
+            assert!((ONE / x).is_empty());
+            assert!(x.recip().is_empty());
+        }
+    }
+
+    #[test]
+    fn recip_agrees_with_div() {
+        const ONE: I = const_interval!(1.0);
+        const INF: f64 = f64::INFINITY;
+        let mut us = sample_f64s(0x9e3779b97f4a7c15, 40000);
+        // Draws from a mix of small, large, zero and infinite bounds to hit every class.
+        let mut bound = || {
+            let (u, v) = (us.next().unwrap(), us.next().unwrap());
+            match (v * 8.0) as u32 {
+                0 => 0.0,
+                1 => -0.0,
+                2 => INF,
+                3 => -INF,
+                4 => (u - 0.5) * 1e-300,
+                5 => (u - 0.5) * 1e300,
+                _ => (u - 0.5) * 20.0,
+            }
+        };
+
+        for _ in 0..10000 {
+            let (a, b) = (bound(), bound());
+            let (a, b) = if a <= b { (a, b) } else { (b, a) };
+            let x = match interval!(a, b) {
+                Ok(x) => x,
+                Err(_) => continue,
+            };
+
+            assert_eq!(x.recip(), ONE / x, "recip({})", x);
+        }
+
+        for x in [
+            const_interval!(0.0, 0.0),
+            const_interval!(-0.0, 0.0),
+            const_interval!(0.0, INF),
+            const_interval!(-INF, -0.0),
+            const_interval!(-INF, 5.0),
+            const_interval!(-5.0, INF),
+            I::ENTIRE,
+            I::EMPTY,
+        ] {
+            assert_eq!(x.recip(), ONE / x, "recip({})", x);
+        }
+    }
+
+    #[test]
+    fn mul_add() {
+        // p(x) = 2x^5 - 4x^3 + x + 1 by Horner's scheme.
+        let cs = [2.0, 0.0, -4.0, 0.0, 1.0, 1.0].map(|c| interval!(c).unwrap());
+        let horner = |x: I| cs[1..].iter().fold(cs[0], |y, &c| y.mul_add(x, c));
+        let naive = |x: I| {
+            const_interval!(2.0) * x.powi(5) + const_interval!(-4.0) * x.powi(3) + x + const_interval!(1.0)
+        };
+
+        for x in [
+            const_interval!(1.0, 2.0),
+            const_interval!(-3.0, -2.5),
+            const_interval!(-1.5, 1.25),
+            const_interval!(-1.2, 1.2),
+            const_interval!(0.1, 0.3),
+        ] {
+            let y = horner(x);
+            for i in 0..=100 {
+                let t = x.inf + (x.sup - x.inf) * (i as f64 / 100.0);
+                let z = cs[1..].iter().fold(cs[0].inf, |y, c| y.mul_add(t, c.inf));
+                assert!(y.contains(z), "p({}) = {} is not contained in {}", t, z, y);
+            }
+        }
+
+        // Horner's scheme suffers less from the dependency problem on these intervals.
+        for x in [const_interval!(1.0, 2.0), const_interval!(-3.0, -2.5), const_interval!(0.1, 0.3)] {
+            assert!(horner(x).subset(naive(x)), "{} is not a subset of {}", horner(x), naive(x));
+        }
+
+        // No rounding occurs with these bounds, so fusing makes no difference.
+        for x in [const_interval!(1.0, 2.0), const_interval!(-3.0, -2.5), const_interval!(-1.5, 1.25)] {
+            assert_eq!(horner(x), cs[1..].iter().fold(cs[0], |y, &c| y * x + c));
+        }
+        assert_eq!(horner(const_interval!(1.0, 2.0)), const_interval!(-13.0, 35.0));
+
+        // The product is not rounded before the addition.
+        let y = const_interval!(0.1).mul_add(const_interval!(10.0), const_interval!(-1.0));
+        let r = 0.1_f64.mul_add(10.0, -1.0);
+        assert_eq!(y, interval!(r.next_down(), r.next_up()).unwrap());
+        assert!(y.inf > 0.0);
+
+        // The product 10^-400 underflows, as well as its error term.
+        let x = const_interval!(1e-200);
+        let y = x.mul_add(x, const_interval!(0.0));
+        assert!(y.inf <= 0.0 && 0.0 < y.sup);
+        let y = (-x).mul_add(x, const_interval!(0.0));
+        assert!(y.inf < 0.0 && 0.0 <= y.sup);
+        let y = x.mul_add(x, const_interval!(1.0));
+        assert!(y.inf <= 1.0 && 1.0 < y.sup);
+
+        assert_eq!(
+            const_interval!(1.0, 2.0).mul_add(const_interval!(1.0, f64::INFINITY), const_interval!(-1.0)),
+            const_interval!(0.0, f64::INFINITY)
+        );
+        assert_eq!(I::ENTIRE.mul_add(const_interval!(0.0), const_interval!(1.0)), const_interval!(1.0));
+        assert!(I::EMPTY.mul_add(const_interval!(1.0), const_interval!(1.0)).is_empty());
+        assert!(const_interval!(1.0).mul_add(const_interval!(1.0), I::EMPTY).is_empty());
+    }
+
+    #[test]
+    fn sum() {
+        let xs = [const_interval!(1.0, 2.0), const_interval!(-3.0, 4.0), const_interval!(0.5)];
+        assert_eq!(xs.iter().sum::<I>(), const_interval!(-1.5, 6.5));
+        assert_eq!(xs.into_iter().sum::<I>(), const_interval!(-1.5, 6.5));
+        assert_eq!(std::iter::empty::<I>().sum::<I>(), const_interval!(0.0));
+        assert!([const_interval!(1.0), I::EMPTY].iter().sum::<I>().is_empty());
+    }
+}
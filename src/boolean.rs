@@ -3,6 +3,81 @@ use crate::interval::*;
 // NOTE: `eq` is implemented in interval.rs
 
 impl Interval{
+    /// Returns `true` if every member of `self` equals every member of `rhs`:
+    ///
+    /// $$
+    /// ∀x ∈ \self, ∀y ∈ \rhs : x = y,
+    /// $$
+    ///
+    /// which holds only when both are the same singleton.
+    /// The result is `false` if either interval is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(1.0).certainly_equal(const_interval!(1.0)));
+    /// assert!(!const_interval!(1.0, 2.0).certainly_equal(const_interval!(1.0, 2.0)));
+    /// assert!(!Interval::EMPTY.certainly_equal(Interval::EMPTY));
+    /// ```
+    ///
+    /// See also: [`Interval::possibly_equal`].
+    pub fn certainly_equal(self, rhs: Self) -> bool {
+        // a = b = c = d
+        self.is_singleton() && rhs.is_singleton() && self.inf == rhs.inf
+    }
+
+    /// Returns `true` if every member of `self` is greater than every member of `rhs`,
+    /// i.e., `rhs.certainly_less(self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(3.0, 4.0).certainly_greater(const_interval!(1.0, 2.0)));
+    /// assert!(!const_interval!(2.0, 4.0).certainly_greater(const_interval!(1.0, 2.0)));
+    /// ```
+    ///
+    /// See also: [`Interval::possibly_greater`].
+    pub fn certainly_greater(self, rhs: Self) -> bool {
+        rhs.certainly_less(self)
+    }
+
+    /// Returns `true` if every member of `self` is less than every member of `rhs`:
+    ///
+    /// $$
+    /// ∀x ∈ \self, ∀y ∈ \rhs : x < y,
+    /// $$
+    ///
+    /// or equivalently,
+    ///
+    /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$ |
+    /// | :----------------: | :--------: | :---------------: |
+    /// | $\self = ∅$        | `false`    | `false`           |
+    /// | $\self = \[a, b\]$ | `false`    | $b < c$           |
+    ///
+    /// Unlike the vacuous truth of the formula, the result is `false` if either interval is empty,
+    /// so that a `true` result of a "certainly" predicate always implies
+    /// a `true` result of the corresponding "possibly" predicate.
+    ///
+    /// Together with [`Interval::possibly_less`], this gives a three-valued comparison:
+    /// `x < y` is true if `certainly_less`, false if not `possibly_less`, and undecided otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(1.0, 2.0).certainly_less(const_interval!(3.0, 4.0)));
+    /// assert!(!const_interval!(1.0, 3.0).certainly_less(const_interval!(3.0, 4.0)));
+    /// assert!(!Interval::EMPTY.certainly_less(Interval::ENTIRE));
+    /// ```
+    ///
+    /// See also: [`Interval::strict_precedes`], which is `true` for empty intervals.
+    pub fn certainly_less(self, rhs: Self) -> bool {
+        // b < c
+        self.sup < rhs.inf
+    }
+
 	/// Returns `true` if `rhs` is a member of `self`: $\rhs ∈ \self$.
     ///
     /// The result is `false` whenever `rhs` is infinite or NaN.
@@ -31,6 +106,31 @@ impl Interval{
         }
     }
 
+    /// Returns `true` if `rhs` is a subset of `self`: $\rhs ⊆ \self$.
+    ///
+    /// This is equivalent to `rhs.subset(self)`, with the arguments in the order
+    /// that reads naturally as "`self` contains `rhs`".
+    ///
+    /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$ |
+    /// | :----------------: | :--------: | :---------------: |
+    /// | $\self = ∅$        | `true`     | `false`           |
+    /// | $\self = \[a, b\]$ | `true`     | $a ≤ c ∧ d ≤ b$   |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(0.0, 10.0).contains_interval(const_interval!(2.0, 3.0)));
+    /// assert!(!const_interval!(0.0, 10.0).contains_interval(const_interval!(5.0, 15.0)));
+    /// assert!(const_interval!(0.0, 10.0).contains_interval(Interval::EMPTY));
+    /// assert!(Interval::ENTIRE.contains_interval(const_interval!(0.0, f64::INFINITY)));
+    /// ```
+    ///
+    /// See also: [`Interval::subset`].
+    pub fn contains_interval(self, rhs: Self) -> bool {
+        rhs.subset(self)
+    }
+
     /// Returns `true` if `self` and `rhs` are disjoint:
     ///
     /// $$
@@ -219,6 +319,134 @@ impl Interval{
         l && r
     }
 
+    /// Returns `true` if `self` and `rhs` have at least one point in common,
+    /// where touching endpoints count as a match:
+    ///
+    /// $$
+    /// \self ∩ \rhs ≠ ∅,
+    /// $$
+    ///
+    /// or equivalently,
+    ///
+    /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$ |
+    /// | :----------------: | :--------: | :---------------: |
+    /// | $\self = ∅$        | `false`    | `false`           |
+    /// | $\self = \[a, b\]$ | `false`    | $a ≤ d ∧ c ≤ b$   |
+    ///
+    /// This is the negation of [`Interval::disjoint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(1.0, 3.0).overlaps_closed(const_interval!(2.0, 4.0)));
+    /// assert!(const_interval!(1.0, 3.0).overlaps_closed(const_interval!(3.0, 4.0)));
+    /// assert!(!const_interval!(1.0, 2.0).overlaps_closed(const_interval!(3.0, 4.0)));
+    /// assert!(!Interval::EMPTY.overlaps_closed(Interval::ENTIRE));
+    /// ```
+    ///
+    /// See also: [`Interval::overlaps_open`].
+    pub fn overlaps_closed(self, rhs: Self) -> bool {
+        !self.disjoint(rhs)
+    }
+
+    /// Returns `true` if `self` and `rhs` overlap without merely touching each other:
+    ///
+    /// $$
+    /// ∃x ∈ \self, ∃y ∈ \rhs, ∃x′ ∈ \self, ∃y′ ∈ \rhs : x < y ∧ y′ < x′,
+    /// $$
+    ///
+    /// or equivalently,
+    ///
+    /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$ |
+    /// | :----------------: | :--------: | :---------------: |
+    /// | $\self = ∅$        | `false`    | `false`           |
+    /// | $\self = \[a, b\]$ | `false`    | $a < d ∧ c < b$   |
+    ///
+    /// Intervals that share only an endpoint, such as $\[1, 3\]$ and $\[3, 4\]$,
+    /// do not overlap in this sense. Neither do two equal singletons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(1.0, 3.0).overlaps_open(const_interval!(2.0, 4.0)));
+    /// assert!(!const_interval!(1.0, 3.0).overlaps_open(const_interval!(3.0, 4.0)));
+    /// assert!(!const_interval!(1.0, 2.0).overlaps_open(const_interval!(3.0, 4.0)));
+    /// assert!(!Interval::EMPTY.overlaps_open(Interval::ENTIRE));
+    /// ```
+    ///
+    /// See also: [`Interval::overlaps_closed`].
+    pub fn overlaps_open(self, rhs: Self) -> bool {
+        // a < d  ∧  c < b
+        !self.precedes(rhs) && !rhs.precedes(self)
+    }
+
+    /// Returns `true` if some member of `self` equals some member of `rhs`:
+    ///
+    /// $$
+    /// ∃x ∈ \self, ∃y ∈ \rhs : x = y,
+    /// $$
+    ///
+    /// which is the same as [`Interval::overlaps_closed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(1.0, 3.0).possibly_equal(const_interval!(3.0, 4.0)));
+    /// assert!(!const_interval!(1.0, 2.0).possibly_equal(const_interval!(3.0, 4.0)));
+    /// ```
+    ///
+    /// See also: [`Interval::certainly_equal`].
+    pub fn possibly_equal(self, rhs: Self) -> bool {
+        self.overlaps_closed(rhs)
+    }
+
+    /// Returns `true` if some member of `self` is greater than some member of `rhs`,
+    /// i.e., `rhs.possibly_less(self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(2.0, 4.0).possibly_greater(const_interval!(1.0, 3.0)));
+    /// assert!(!const_interval!(1.0, 2.0).possibly_greater(const_interval!(2.0, 4.0)));
+    /// ```
+    ///
+    /// See also: [`Interval::certainly_greater`].
+    pub fn possibly_greater(self, rhs: Self) -> bool {
+        rhs.possibly_less(self)
+    }
+
+    /// Returns `true` if some member of `self` is less than some member of `rhs`:
+    ///
+    /// $$
+    /// ∃x ∈ \self, ∃y ∈ \rhs : x < y,
+    /// $$
+    ///
+    /// or equivalently,
+    ///
+    /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$ |
+    /// | :----------------: | :--------: | :---------------: |
+    /// | $\self = ∅$        | `false`    | `false`           |
+    /// | $\self = \[a, b\]$ | `false`    | $a < d$           |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(1.0, 3.0).possibly_less(const_interval!(2.0, 4.0)));
+    /// assert!(!const_interval!(3.0, 4.0).possibly_less(const_interval!(1.0, 3.0)));
+    /// assert!(!const_interval!(1.0).possibly_less(const_interval!(1.0)));
+    /// ```
+    ///
+    /// See also: [`Interval::certainly_less`].
+    pub fn possibly_less(self, rhs: Self) -> bool {
+        // a < d
+        self.inf < rhs.sup
+    }
+
     /// Returns `true` if `self` is to the left of `rhs` but may touch it:
     ///
     /// $$
@@ -352,4 +580,101 @@ impl Interval{
     pub(crate) fn either_empty(self, rhs: Self) -> bool {
         self.is_empty() | rhs.is_empty()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn certainly_possibly() {
+        // (x, y, certainly_less, possibly_less, certainly_equal, possibly_equal)
+        let cases = [
+            // Disjoint.
+            (const_interval!(1.0, 2.0), const_interval!(3.0, 4.0), true, true, false, false),
+            (const_interval!(3.0, 4.0), const_interval!(1.0, 2.0), false, false, false, false),
+            // Touching.
+            (const_interval!(1.0, 3.0), const_interval!(3.0, 4.0), false, true, false, true),
+            (const_interval!(3.0, 4.0), const_interval!(1.0, 3.0), false, false, false, true),
+            // Overlapping.
+            (const_interval!(1.0, 3.0), const_interval!(2.0, 4.0), false, true, false, true),
+            (const_interval!(1.0, 4.0), const_interval!(2.0, 3.0), false, true, false, true),
+            // Singletons.
+            (const_interval!(1.0), const_interval!(1.0), false, false, true, true),
+            (const_interval!(1.0), const_interval!(2.0), true, true, false, false),
+            // Unbounded and empty.
+            (I::ENTIRE, I::ENTIRE, false, true, false, true),
+            (const_interval!(f64::NEG_INFINITY, 0.0), const_interval!(1.0, f64::INFINITY), true, true, false, false),
+            (I::EMPTY, I::ENTIRE, false, false, false, false),
+            (I::ENTIRE, I::EMPTY, false, false, false, false),
+            (I::EMPTY, I::EMPTY, false, false, false, false),
+        ];
+        for (x, y, cl, pl, ce, pe) in cases {
+            assert_eq!(x.certainly_less(y), cl, "{:?} {:?}", x, y);
+            assert_eq!(x.possibly_less(y), pl, "{:?} {:?}", x, y);
+            assert_eq!(y.certainly_greater(x), cl, "{:?} {:?}", x, y);
+            assert_eq!(y.possibly_greater(x), pl, "{:?} {:?}", x, y);
+            assert_eq!(x.certainly_equal(y), ce, "{:?} {:?}", x, y);
+            assert_eq!(x.possibly_equal(y), pe, "{:?} {:?}", x, y);
+
+            // Certainly implies possibly.
+            assert!(!x.certainly_less(y) || x.possibly_less(y));
+            assert!(!x.certainly_equal(y) || x.possibly_equal(y));
+        }
+    }
+
+    #[test]
+    fn contains_interval() {
+        let x = const_interval!(0.0, 10.0);
+        assert!(x.contains_interval(x));
+        assert!(x.contains_interval(const_interval!(2.0, 3.0)));
+        assert!(x.contains_interval(const_interval!(10.0)));
+        assert!(!x.contains_interval(const_interval!(-1.0, 3.0)));
+        assert!(!const_interval!(2.0, 3.0).contains_interval(x));
+
+        // Unbounded intervals.
+        let pos = const_interval!(0.0, f64::INFINITY);
+        assert!(pos.contains_interval(x));
+        assert!(!x.contains_interval(pos));
+        assert!(!pos.contains_interval(I::ENTIRE));
+        assert!(I::ENTIRE.contains_interval(pos));
+        assert!(I::ENTIRE.contains_interval(I::ENTIRE));
+
+        // Empty intervals.
+        assert!(x.contains_interval(I::EMPTY));
+        assert!(I::EMPTY.contains_interval(I::EMPTY));
+        assert!(!I::EMPTY.contains_interval(x));
+    }
+
+    #[test]
+    fn overlaps() {
+        let x = const_interval!(1.0, 3.0);
+
+        // Overlapping.
+        assert!(x.overlaps_closed(const_interval!(2.0, 4.0)));
+        assert!(x.overlaps_open(const_interval!(2.0, 4.0)));
+        assert!(x.overlaps_closed(const_interval!(2.0)));
+        assert!(x.overlaps_open(const_interval!(2.0)));
+
+        // Touching.
+        assert!(x.overlaps_closed(const_interval!(3.0, 4.0)));
+        assert!(!x.overlaps_open(const_interval!(3.0, 4.0)));
+        assert!(x.overlaps_closed(const_interval!(0.0, 1.0)));
+        assert!(!x.overlaps_open(const_interval!(0.0, 1.0)));
+        assert!(x.overlaps_closed(const_interval!(1.0)));
+        assert!(!x.overlaps_open(const_interval!(1.0)));
+        assert!(const_interval!(1.0).overlaps_closed(const_interval!(1.0)));
+        assert!(!const_interval!(1.0).overlaps_open(const_interval!(1.0)));
+
+        // Disjoint.
+        assert!(!x.overlaps_closed(const_interval!(4.0, 5.0)));
+        assert!(!x.overlaps_open(const_interval!(4.0, 5.0)));
+
+        assert!(I::ENTIRE.overlaps_open(I::ENTIRE));
+        assert!(!I::EMPTY.overlaps_closed(I::EMPTY));
+        assert!(!I::EMPTY.overlaps_open(I::EMPTY));
+        assert!(!x.overlaps_closed(I::EMPTY));
+        assert!(!x.overlaps_open(I::EMPTY));
+    }
+}
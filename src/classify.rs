@@ -2,7 +2,19 @@ use crate::interval::*;
 use std::mem::transmute;
 
 impl Interval {
-    pub(crate) fn classify(self) -> IntervalClass {
+    /// Returns the class of `self` according to the signs of its bounds.
+    ///
+    /// See [`IntervalClass`] for the list of classes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-1.0, 2.0).classify(), IntervalClass::M);
+    /// assert_eq!(const_interval!(0.0, 2.0).classify(), IntervalClass::P0);
+    /// assert_eq!(Interval::EMPTY.classify(), IntervalClass::E);
+    /// ```
+    pub fn classify(self) -> IntervalClass {
 
         // let ge_zero = bitmask(ge(self.rep, zero)) as u8;
 		let inf_ge_zero = (-self.inf >= 0.0) as u8;
@@ -17,9 +29,58 @@ impl Interval {
         unsafe { transmute((le_zero << 2) | ge_zero) }
     }
 
-    pub(crate) fn classify2(self, rhs: Self) -> IntervalClass2 {
+    /// Returns the pair of the classes of `self` and `rhs`, which can be matched at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(-1.0, 2.0);
+    /// let y = const_interval!(3.0, 4.0);
+    /// assert_eq!(x.classify2(y), IntervalClass2::M_P1);
+    /// ```
+    pub fn classify2(self, rhs: Self) -> IntervalClass2 {
         unsafe { transmute(((self.classify() as u8) << 4) | rhs.classify() as u8) }
     }
+
+    /// Returns whether `self` is empty, entire, bounded or half-bounded,
+    /// examining the bounds only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(Interval::EMPTY.special_state(), SpecialState::Empty);
+    /// assert_eq!(Interval::ENTIRE.special_state(), SpecialState::Entire);
+    /// assert_eq!(const_interval!(1.0, 2.0).special_state(), SpecialState::Bounded);
+    /// assert_eq!(const_interval!(0.0, f64::INFINITY).special_state(), SpecialState::HalfBounded);
+    /// ```
+    pub fn special_state(self) -> SpecialState {
+        // For the empty interval, both comparisons are false.
+        let inf_unbounded = self.inf == f64::NEG_INFINITY;
+        let sup_unbounded = self.sup == f64::INFINITY;
+
+        match (inf_unbounded, sup_unbounded) {
+            (true, true) => SpecialState::Entire,
+            (true, false) | (false, true) => SpecialState::HalfBounded,
+            (false, false) if self.is_empty() => SpecialState::Empty,
+            (false, false) => SpecialState::Bounded,
+        }
+    }
+}
+
+/// The state of an interval with respect to emptiness and boundedness,
+/// returned by [`Interval::special_state`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SpecialState {
+    /// $∅$.
+    Empty,
+    /// $\[-∞, +∞\]$.
+    Entire,
+    /// $\[a, b\]$, where $a$ and $b$ are finite.
+    Bounded,
+    /// $\[a, +∞\]$ or $\[-∞, b\]$, where $a$ and $b$ are finite.
+    HalfBounded,
 }
 
 // The following codes are used to represent the class of an interval:
@@ -37,17 +98,26 @@ impl Interval {
 //    P* | a = 0 ∧ 0 ≤ b
 // * These codes are used only in comments.
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// The class of an interval $\[a, b\]$ according to the signs of its bounds,
+/// returned by [`Interval::classify`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[repr(u8)]
-pub(crate) enum IntervalClass {
+pub enum IntervalClass {
     // Each bit represents b ≤ 0, -a ≤ 0, b ≥ 0 and -a ≥ 0, respectively.
-    E = 0b0000,  // empty
-    M = 0b0011,  // b > 0 ∧ -a > 0
-    N0 = 0b1011, // b = 0 ∧ -a > 0
-    N1 = 0b1001, // b < 0 ∧ -a > 0
-    P0 = 0b0111, // b > 0 ∧ -a = 0
-    P1 = 0b0110, // b > 0 ∧ -a < 0
-    Z = 0b1111,  // b = 0 ∧ -a = 0
+    /// $∅$.
+    E = 0b0000,
+    /// $a < 0 < b$.
+    M = 0b0011,
+    /// $a < 0 ∧ b = 0$.
+    N0 = 0b1011,
+    /// $b < 0$.
+    N1 = 0b1001,
+    /// $a = 0 ∧ 0 < b$.
+    P0 = 0b0111,
+    /// $0 < a$.
+    P1 = 0b0110,
+    /// $a = b = 0$.
+    Z = 0b1111,
 }
 
 macro_rules! discr {
@@ -56,11 +126,15 @@ macro_rules! discr {
     };
 }
 
-#[allow(dead_code)]
+/// The pair of the classes of two intervals, returned by [`Interval::classify2`].
+///
+/// The variant `X_Y` means that the first interval is of class `X`
+/// and the second one is of class `Y` (see [`IntervalClass`]).
+#[allow(missing_docs)]
 #[allow(non_camel_case_types)] // We could rename E_E to ExE, etc., but that would degrade the legibility.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[repr(u8)]
-pub(crate) enum IntervalClass2 {
+pub enum IntervalClass2 {
     E_E = discr!(E, E),
     E_M = discr!(E, M),
     E_N0 = discr!(E, N0),
@@ -137,4 +211,16 @@ mod tests {
         assert_eq!(const_interval!(1.0, 1.0).classify(), P1);
         assert_eq!(I::zero().classify(), Z);
     }
+
+    #[test]
+    fn special_state() {
+        use SpecialState::*;
+        assert_eq!(I::EMPTY.special_state(), Empty);
+        assert_eq!(I::ENTIRE.special_state(), Entire);
+        assert_eq!(const_interval!(-1.0, 1.0).special_state(), Bounded);
+        assert_eq!(const_interval!(0.0).special_state(), Bounded);
+        assert_eq!(const_interval!(f64::MIN, f64::MAX).special_state(), Bounded);
+        assert_eq!(const_interval!(0.0, f64::INFINITY).special_state(), HalfBounded);
+        assert_eq!(const_interval!(f64::NEG_INFINITY, 0.0).special_state(), HalfBounded);
+    }
 }
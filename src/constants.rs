@@ -18,12 +18,18 @@ impl Interval {
     /// The tightest interval enclosing $1 / \sqrt{2}$.
     pub const FRAC_1_SQRT_2: Self = const_interval!(0.7071067811865475, 0.7071067811865476);
 
+    /// The tightest interval enclosing $180 / π$, the number of degrees in a radian.
+    pub const FRAC_180_PI: Self = const_interval!(57.295779513082316, 57.29577951308232);
+
     /// The tightest interval enclosing $2 / π$.
     pub const FRAC_2_PI: Self = const_interval!(0.6366197723675813, 0.6366197723675814);
 
     /// The tightest interval enclosing $2 / \sqrt{π}$.
     pub const FRAC_2_SQRT_PI: Self = const_interval!(1.1283791670955126, 1.1283791670955128);
 
+    /// The tightest interval enclosing $π / 180$, the number of radians in a degree.
+    pub const FRAC_PI_180: Self = const_interval!(0.017453292519943295, 0.0174532925199433);
+
     /// The tightest interval enclosing $π / 2$.
     pub const FRAC_PI_2: Self = const_interval!(1.5707963267948966, 1.5707963267948968);
 
@@ -0,0 +1,257 @@
+use crate::interval::*;
+use forward_ref::*;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The decoration of a [`DecInterval`], which tells how an interval has been computed.
+///
+/// Decorations are ordered as `Ill < Trv < Def < Dac < Com`, from the least to the most informative.
+/// The decoration of the result of an operation is the minimum of the decorations of the arguments
+/// and the decoration that describes the operation itself on the arguments.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum Decoration {
+    /// “Ill-formed”: the interval is not a valid interval (NaI).
+    Ill = 0,
+    /// “Trivial”: nothing is known about the computation.
+    Trv = 4,
+    /// “Defined”: every operation has been defined on its arguments.
+    Def = 8,
+    /// “Defined and continuous”: every operation has been defined and continuous on its arguments.
+    Dac = 12,
+    /// “Common”: in addition to `Dac`, every interval involved has been nonempty and bounded.
+    Com = 16,
+}
+
+/// An interval paired with a [`Decoration`].
+///
+/// # Examples
+///
+/// ```
+/// use inari_wasm::*;
+/// let x = DecInterval::new(const_interval!(-1.0, 4.0));
+/// let y = DecInterval::new(const_interval!(1.0, 4.0));
+/// assert_eq!(x.sqrt().decoration(), Decoration::Trv);
+/// assert_eq!(y.sqrt().decoration(), Decoration::Com);
+/// assert_eq!((x + y.sqrt()).decoration(), Decoration::Com);
+/// assert_eq!((x + x.sqrt()).decoration(), Decoration::Trv);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DecInterval {
+    pub(crate) x: Interval,
+    pub(crate) d: Decoration,
+}
+
+impl DecInterval {
+    /// $∅$ decorated with [`Decoration::Trv`].
+    pub const EMPTY: Self = Self { x: Interval::EMPTY, d: Decoration::Trv };
+
+    /// $\[-∞, +∞\]$ decorated with [`Decoration::Dac`].
+    pub const ENTIRE: Self = Self { x: Interval::ENTIRE, d: Decoration::Dac };
+
+    /// The NaI (Not an Interval), decorated with [`Decoration::Ill`].
+    pub const NAI: Self = Self { x: Interval::EMPTY, d: Decoration::Ill };
+
+    /// Creates a [`DecInterval`] from `x` with the most informative decoration:
+    ///
+    /// | `x`                 | Decoration |
+    /// | ------------------- | ---------- |
+    /// | Nonempty, bounded   | `Com`      |
+    /// | Nonempty, unbounded | `Dac`      |
+    /// | Empty               | `Trv`      |
+    pub fn new(x: Interval) -> Self {
+        let d = if x.is_common_interval() {
+            Decoration::Com
+        } else if x.is_empty() {
+            Decoration::Trv
+        } else {
+            Decoration::Dac
+        };
+        Self { x, d }
+    }
+
+    /// Creates a [`DecInterval`] from `x` and `d`.
+    ///
+    /// The decoration is lowered if it is inconsistent with `x`:
+    /// an empty interval is always decorated with `Trv`,
+    /// and an unbounded interval with `Com` is decorated with `Dac` instead.
+    /// If `d` is [`Decoration::Ill`], the result is [`DecInterval::NAI`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = DecInterval::set_dec(const_interval!(1.0, 2.0), Decoration::Def);
+    /// assert_eq!(x.decoration(), Decoration::Def);
+    /// let x = DecInterval::set_dec(Interval::ENTIRE, Decoration::Com);
+    /// assert_eq!(x.decoration(), Decoration::Dac);
+    /// let x = DecInterval::set_dec(Interval::EMPTY, Decoration::Com);
+    /// assert_eq!(x.decoration(), Decoration::Trv);
+    /// ```
+    pub fn set_dec(x: Interval, d: Decoration) -> Self {
+        if d == Decoration::Ill {
+            Self::NAI
+        } else if x.is_empty() {
+            Self::EMPTY
+        } else if d == Decoration::Com && !x.is_common_interval() {
+            Self { x, d: Decoration::Dac }
+        } else {
+            Self { x, d }
+        }
+    }
+
+    /// Returns the decoration of `self`.
+    pub fn decoration(self) -> Decoration {
+        self.d
+    }
+
+    /// Returns the interval part of `self`, or `None` if `self` is NaI.
+    pub fn interval(self) -> Option<Interval> {
+        if self.is_nai() {
+            None
+        } else {
+            Some(self.x)
+        }
+    }
+
+    /// Returns `true` if `self` is NaI.
+    pub fn is_nai(self) -> bool {
+        self.d == Decoration::Ill
+    }
+
+    /// Returns the square root of `self`.
+    ///
+    /// The decoration is lowered to `Trv` unless `self` is a subset of the domain $\[0, +∞)$.
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        let defined = self.x.inf >= 0.0;
+        Self::decorate(self.x.sqrt(), defined, self.d)
+    }
+
+    /// Returns the natural logarithm of `self`.
+    ///
+    /// The decoration is lowered to `Trv` unless `self` is a subset of the domain $(0, +∞)$.
+    #[must_use]
+    pub fn ln(self) -> Self {
+        let defined = self.x.inf > 0.0;
+        Self::decorate(self.x.ln(), defined, self.d)
+    }
+
+    // Decorates the result `y` of a function evaluated on arguments whose decorations
+    // have the minimum `d`, where `defined` tells whether the function is defined
+    // and continuous on the arguments.
+    fn decorate(y: Interval, defined: bool, d: Decoration) -> Self {
+        let d_op = if !defined {
+            Decoration::Trv
+        } else if y.is_common_interval() {
+            Decoration::Com
+        } else {
+            Decoration::Dac
+        };
+        Self::set_dec(y, d.min(d_op))
+    }
+}
+
+impl Neg for DecInterval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::set_dec(-self.x, self.d)
+    }
+}
+
+forward_ref_unop!(impl Neg, neg for DecInterval);
+
+macro_rules! impl_dec_op {
+    ($Op:ident, $op:ident, $defined:expr) => {
+        impl $Op for DecInterval {
+            type Output = Self;
+
+            fn $op(self, rhs: Self) -> Self {
+                // Whether the operation is defined and continuous, given the second argument.
+                let defined: fn(Interval) -> bool = $defined;
+                Self::decorate(self.x.$op(rhs.x), defined(rhs.x), self.d.min(rhs.d))
+            }
+        }
+
+        forward_ref_binop!(impl $Op, $op for DecInterval, DecInterval);
+    };
+}
+
+impl_dec_op!(Add, add, |_| true);
+impl_dec_op!(Sub, sub, |_| true);
+impl_dec_op!(Mul, mul, |_| true);
+// Division is undefined where the divisor is zero.
+impl_dec_op!(Div, div, |y| !y.contains(0.0));
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use DecInterval as DI;
+    use Decoration::*;
+    use Interval as I;
+
+    #[test]
+    fn new_set_dec() {
+        assert_eq!(DI::new(const_interval!(1.0, 2.0)).decoration(), Com);
+        assert_eq!(DI::new(const_interval!(1.0, f64::INFINITY)).decoration(), Dac);
+        assert_eq!(DI::new(I::ENTIRE), DI::ENTIRE);
+        assert_eq!(DI::new(I::EMPTY), DI::EMPTY);
+
+        assert_eq!(DI::set_dec(const_interval!(1.0, 2.0), Trv).decoration(), Trv);
+        assert_eq!(DI::set_dec(I::ENTIRE, Com).decoration(), Dac);
+        assert_eq!(DI::set_dec(I::EMPTY, Def), DI::EMPTY);
+        assert_eq!(DI::set_dec(const_interval!(1.0, 2.0), Ill), DI::NAI);
+
+        assert!(DI::NAI.is_nai());
+        assert_eq!(DI::NAI.interval(), None);
+        assert_eq!(DI::EMPTY.interval(), Some(I::EMPTY));
+        assert!(Ill < Trv && Trv < Def && Def < Dac && Dac < Com);
+    }
+
+    #[test]
+    fn sqrt_ln() {
+        let x = DI::new(const_interval!(-1.0, 4.0));
+        assert_eq!(x.sqrt().interval(), Some(const_interval!(0.0, 2.0)));
+        assert_eq!(x.sqrt().decoration(), Trv);
+        assert_eq!(DI::new(const_interval!(1.0, 4.0)).sqrt().decoration(), Com);
+        assert_eq!(DI::new(const_interval!(0.0, 4.0)).sqrt().decoration(), Com);
+        assert_eq!(DI::new(const_interval!(0.0, f64::INFINITY)).sqrt().decoration(), Dac);
+        assert_eq!(DI::new(const_interval!(-2.0, -1.0)).sqrt(), DI::EMPTY);
+
+        assert_eq!(DI::new(const_interval!(1.0, 2.0)).ln().decoration(), Com);
+        // ln is not defined at 0, and the result is unbounded.
+        assert_eq!(DI::new(const_interval!(0.0, 1.0)).ln().decoration(), Trv);
+        assert_eq!(DI::ENTIRE.ln().decoration(), Trv);
+
+        // The decoration of the argument is propagated.
+        assert_eq!(DI::set_dec(const_interval!(1.0, 4.0), Def).sqrt().decoration(), Def);
+        assert!(DI::NAI.sqrt().is_nai());
+        assert!(DI::NAI.ln().is_nai());
+    }
+
+    #[test]
+    fn arith() {
+        let x = DI::new(const_interval!(1.0, 2.0));
+        let y = DI::new(const_interval!(-1.0, 3.0));
+        for z in [x + y, x - y, x * y, y / x, -x] {
+            assert_eq!(z.decoration(), Com);
+        }
+        assert_eq!((x + y).interval(), Some(const_interval!(0.0, 5.0)));
+
+        // Division by an interval that contains zero.
+        assert_eq!((x / y).decoration(), Trv);
+        assert_eq!((x / DI::new(const_interval!(0.0, 1.0))).decoration(), Trv);
+
+        // Overflow to an unbounded result.
+        let big = DI::new(const_interval!(f64::MAX, f64::MAX));
+        assert_eq!((big + big).decoration(), Dac);
+
+        // The minimum of the decorations is taken.
+        let w = DI::set_dec(const_interval!(1.0, 2.0), Def);
+        assert_eq!((x + w).decoration(), Def);
+        assert_eq!((x + DI::ENTIRE).decoration(), Dac);
+        assert_eq!(x + DI::EMPTY, DI::EMPTY);
+        assert!((x * DI::NAI).is_nai());
+        assert!((DI::NAI / x).is_nai());
+    }
+}
@@ -1,5 +1,9 @@
-use crate::{classify::*, const_interval, interval::*};
-use libm;
+use crate::{
+    arith::{div_down, div_up, mul_down, mul_up},
+    classify::*,
+    const_interval,
+    interval::*,
+};
 
 fn rem_euclid_2(x: f64) -> f64 {
     if 2.0 * (x / 2.0).floor() == x {
@@ -8,18 +12,249 @@ fn rem_euclid_2(x: f64) -> f64 {
         1.0
     }
 }
+
+// The point at which Γ attains its minimum on (0, ∞), rounded to nearest.
+const GAMMA_X0: f64 = 1.4616321449683622;
+// Lower bounds of Γ(x_0) and ln Γ(x_0).
+const GAMMA_MIN: f64 = 0.8856031944108886;
+const LN_GAMMA_MIN: f64 = -0.12148629053584963;
+
+// The digamma function ψ = Γ' / Γ, with an absolute error of about 1e-11 away from the poles.
+fn digamma(x: f64) -> f64 {
+    use std::f64::consts::PI;
+    if x < 0.5 {
+        // The reflection formula ψ(1 - x) - ψ(x) = π cot(π x).
+        return digamma(1.0 - x) - PI / f64::tan(PI * x);
+    }
+
+    // The recurrence ψ(x + 1) = ψ(x) + 1/x, followed by the asymptotic expansion.
+    let (mut x, mut r) = (x, 0.0);
+    while x < 6.0 {
+        r -= 1.0 / x;
+        x += 1.0;
+    }
+    let f = 1.0 / (x * x);
+    r + x.ln() - 0.5 / x
+        - f * (1.0 / 12.0 - f * (1.0 / 120.0 - f * (1.0 / 252.0 - f * (1.0 / 240.0 - f / 132.0))))
+}
+
+// sin(π x), where the reduction of x to [0, 1/4] is exact,
+// so that the result is exact at the integers and the half-integers.
+// The reduction is done on |x|, since `rem_euclid` rounds for negative numbers,
+// e.g., (-1e-300).rem_euclid(2.0) == 2.0, and the sign is restored by the odd symmetry.
+fn sin_pi_point(x: f64) -> f64 {
+    use std::f64::consts::PI;
+    let r = x.abs().rem_euclid(2.0);
+    let (s, r) = if r >= 1.0 { (-1.0, r - 1.0) } else { (1.0, r) };
+    let r = if r > 0.5 { 1.0 - r } else { r };
+    let s = if x < 0.0 { -s } else { s };
+    s * if r > 0.25 {
+        f64::cos(PI * (0.5 - r))
+    } else {
+        f64::sin(PI * r)
+    }
+}
+
+// cos(π x), where the reduction of x to [0, 1/4] is exact.
+fn cos_pi_point(x: f64) -> f64 {
+    use std::f64::consts::PI;
+    let r = x.abs().rem_euclid(2.0);
+    let r = if r > 1.0 { 2.0 - r } else { r };
+    let (s, r) = if r > 0.5 { (-1.0, 1.0 - r) } else { (1.0, r) };
+    s * if r > 0.25 {
+        f64::sin(PI * (0.5 - r))
+    } else {
+        f64::cos(PI * r)
+    }
+}
+
+// tan(π x), where the reduction of x to [0, 1/4] is exact.
+// As in `sin_pi_point`, the reduction is done on |x|.
+// Returns -∞ at the poles, which is the limit from the right.
+fn tan_pi_point(x: f64) -> f64 {
+    use std::f64::consts::PI;
+    let r = x.abs().rem_euclid(1.0);
+    if r == 0.5 {
+        return f64::NEG_INFINITY;
+    }
+    let (s, r) = if r > 0.5 { (-1.0, 1.0 - r) } else { (1.0, r) };
+    let s = if x < 0.0 { -s } else { s };
+    s * if r == 0.25 {
+        1.0
+    } else if r > 0.25 {
+        1.0 / f64::tan(PI * (0.5 - r))
+    } else {
+        f64::tan(PI * r)
+    }
+}
+
+// Returns `r^n` for `r` > 0 if it is computed exactly by binary exponentiation,
+// which is checked for each product with fused multiply-add; otherwise, `None`.
+fn powi_exact(r: f64, n: u32) -> Option<f64> {
+    let mul = |a: f64, b: f64| {
+        let p = a * b;
+        (p.is_normal() && a.mul_add(b, -p) == 0.0).then_some(p)
+    };
+
+    let (mut p, mut base, mut n) = (1.0, r, n);
+    loop {
+        if n & 1 == 1 {
+            p = mul(p, base)?;
+        }
+        n >>= 1;
+        if n == 0 {
+            return Some(p);
+        }
+        base = mul(base, base)?;
+    }
+}
+
+// Returns `true` if `r` is exactly x^c for `x` ≥ 0 and `c` > 0, which is determined
+// only for 0, 1, +∞ and for the dyadic exponents c = m / 2^k with small m and k,
+// by checking r^(2^k) = x^m exactly. Otherwise, `false` is returned.
+fn powf_is_exact(x: f64, c: f64, r: f64) -> bool {
+    if x == 0.0 || x == 1.0 || x == f64::INFINITY {
+        return true;
+    }
+
+    let (mut m, mut q) = (c, 1);
+    while m.fract() != 0.0 && q < 64 {
+        m *= 2.0;
+        q *= 2;
+    }
+    if m.fract() != 0.0 || m > 64.0 || r == 0.0 || !r.is_finite() {
+        return false;
+    }
+    let rq = powi_exact(r, q);
+    rq.is_some() && rq == powi_exact(x, m as u32)
+}
+
+// Returns |x|^n for `n` ≥ 1 by binary exponentiation, where `mul` is a multiplication
+// rounded in a fixed direction. Every factor is nonnegative, so the result is rounded
+// in the same direction. Multiplication by the initial 1 is avoided, since the directed
+// multiplications widen subnormal products even if they are exact.
+fn pow_abs(x: f64, n: u32, mul: impl Fn(f64, f64) -> f64) -> f64 {
+    let (mut base, mut n) = (x.abs(), n);
+    while n & 1 == 0 {
+        base = mul(base, base);
+        n >>= 1;
+    }
+    let mut p = base;
+    n >>= 1;
+    while n != 0 {
+        base = mul(base, base);
+        if n & 1 == 1 {
+            p = mul(p, base);
+        }
+        n >>= 1;
+    }
+    p
+}
+
+// Returns |x|^n for `n` ≥ 1 rounded toward -∞.
+fn pow_abs_down(x: f64, n: u32) -> f64 {
+    // The product of nonnegative numbers is nonnegative, even if mul_down underflows below 0.
+    pow_abs(x, n, |a, b| mul_down(a, b).max(0.0))
+}
+
+// Returns |x|^n for `n` ≥ 1 rounded toward +∞.
+fn pow_abs_up(x: f64, n: u32) -> f64 {
+    pow_abs(x, n, mul_up)
+}
+
+// Returns x^n for `n` ≥ 1 rounded toward -∞. The sign of -0.0 is respected for odd `n`.
+fn pow_down(x: f64, n: u32) -> f64 {
+    if x.is_sign_negative() && n % 2 == 1 {
+        -pow_abs_up(x, n)
+    } else {
+        pow_abs_down(x, n)
+    }
+}
+
+// Returns x^n for `n` ≥ 1 rounded toward +∞. The sign of -0.0 is respected for odd `n`.
+fn pow_up(x: f64, n: u32) -> f64 {
+    if x.is_sign_negative() && n % 2 == 1 {
+        -pow_abs_down(x, n)
+    } else {
+        pow_abs_up(x, n)
+    }
+}
+
+// Returns lower and upper bounds of the `n`-th root of `x` > 0.
+// `libm::pow(x, 1/n)` is not exact even for perfect powers, since 1/n is rounded,
+// and the relative error grows with |ln x|, so it is refined by a Newton step.
+// If no number adjacent to the estimate is the exact root, the bounds are moved outward
+// until their `n`-th powers are on the respective sides of `x`.
+fn root_bounds(x: f64, n: u32) -> (f64, f64) {
+    if x == 0.0 || x == f64::INFINITY || n == 1 {
+        return (x, x);
+    }
+
+    // The candidates are verified with the directed powers below, which are too loose
+    // to verify anything in the subnormal range. Thus a tiny `x` is scaled by 2^(k n)
+    // into the normal range, and the root is scaled back by 2^-k, which is exact
+    // since the root is never subnormal.
+    const TINY: f64 = 9.332636185032189e-302; // 2^-1000
+    if x < TINY {
+        let kn_min = -1000 - libm::ilogb(x) as i64;
+        let k = (kn_min + n as i64 - 1) / n as i64;
+        let kn = k * n as i64;
+        if kn > 2000 {
+            // `n` is so large that the scaled `x` would overflow; the root lies in (0, 1).
+            return (0.0, 1.0);
+        }
+        let (lo, hi) = root_bounds(libm::scalbn(x, kn as i32), n);
+        return (libm::scalbn(lo, -k as i32), libm::scalbn(hi, -k as i32));
+    }
+
+    let mut r = libm::pow(x, 1.0 / n as f64);
+    r -= (r - x / libm::pow(r, (n - 1) as f64)) / n as f64;
+
+    for r in [r, r.next_down(), r.next_up()] {
+        if powi_exact(r, n) == Some(x) {
+            return (r, r);
+        }
+    }
+
+    // lo^n ≤ pow_up(lo, n) ≤ x ≤ pow_down(hi, n) ≤ hi^n.
+    let mut lo = r;
+    while pow_up(lo, n) > x {
+        lo = lo.next_down();
+    }
+    let mut hi = r;
+    while pow_down(hi, n) < x {
+        hi = hi.next_up();
+    }
+    (lo, hi)
+}
+
+// Creates [a, b] from bounds of a power of a finite number, which are finite mathematically
+// but can overflow to ±∞ in f64. An overflowed lower bound +∞ or upper bound -∞ would give
+// [+∞, +∞] or [-∞, -∞], which contains no real number, so they are replaced by ±MAX.
+fn with_overflowed_bounds(a: f64, b: f64) -> Interval {
+    Interval::with_infsup_raw(
+        if a == f64::INFINITY { f64::MAX } else { a },
+        if b == f64::NEG_INFINITY { f64::MIN } else { b },
+    )
+}
+
 macro_rules! impl_log {
     ($(#[$meta:meta])* $f:ident, $f_real:expr) => {
+        impl_log!($(#[$meta])* $f, $f_real, 0.0);
+    };
+
+    // The domain of the point function is `($lo, ∞)`.
+    ($(#[$meta:meta])* $f:ident, $f_real:expr, $lo:expr) => {
         $(#[$meta])*
         #[allow(dead_code)]
         #[must_use]
         pub fn $f(self) -> Self {
             // See the comment in atanh_impl.
-            const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+            const DOM: Interval = const_interval!($lo, f64::INFINITY);
             let x = self.intersection(DOM);
 
             let (a, b) = (x.inf, x.sup);
-            if x.is_empty() || b <= 0.0 {
+            if x.is_empty() || b <= $lo {
                 return Self::EMPTY;
             }
 
@@ -40,6 +275,22 @@ macro_rules! impl_mono_inc {
             Self::with_infsup_raw($f_real(self.inf), $f_real(self.sup))
         }
     };
+
+    // `$range` is returned for the entire interval without evaluating the function.
+    ($(#[$meta:meta])* $f:ident, $f_real:expr, $range:expr) => {
+        $(#[$meta])*
+        #[must_use]
+        pub fn $f(self) -> Self {
+            if self.is_empty() {
+                return self;
+            }
+            if self.is_entire() {
+                return $range;
+            }
+
+            Self::with_infsup_raw($f_real(self.inf), $f_real(self.sup))
+        }
+    };
 }
 
 impl Interval {
@@ -110,7 +361,8 @@ impl Interval {
         /// | ------ | ----- |
         /// | $\R$   | $\R$  |
         asinh,
-        f64::asinh
+        f64::asinh,
+        Self::ENTIRE
     );
     impl_mono_inc!(
         /// Returns the inverse tangent of `self`.
@@ -121,7 +373,9 @@ impl Interval {
         /// | ------ | ------------- |
         /// | $\R$   | $(-π/2, π/2)$ |
         atan,
-		f64::atan
+		f64::atan,
+        // f64::atan(±∞) is ±π/2 rounded to nearest, which is inside the range.
+        Self::with_infsup_raw(-Self::FRAC_PI_2.sup, Self::FRAC_PI_2.sup)
     );
 
     /// Returns the angle of the point $(\rhs, \self)$ measured counterclockwise from the positive
@@ -145,11 +399,16 @@ impl Interval {
         match x.classify2(y) {
             E_E | E_M | E_N0 | E_N1 | E_P0 | E_P1 | E_Z | M_E | N0_E | N1_E | P0_E | P1_E | Z_E
             | Z_Z => Self::EMPTY,
+            // These cases contain the negative x-axis, where the angle is π,
+            // as well as points just below it, where the angle approaches -π.
+            // Thus [-π, π] is the tightest interval that encloses the range.
             M_M | M_N0 | N0_M | N0_N0 => Self::with_infsup_raw(-Self::PI.sup, Self::PI.sup),
 
             // First quadrant
             P0_P0 => Self::with_infsup_raw(0.0, Self::FRAC_PI_2.sup),
-            P0_P1 | P1_P0 | P1_P1 | P1_Z | Z_P1 => Self::with_infsup_raw(f64::atan2(c, b), f64::atan2(d, a)),
+            // f64::atan2(d, 0.0) is π/2 rounded to nearest, which is not an enclosure.
+            P0_P1 => Self::with_infsup_raw(f64::atan2(c, b), Self::FRAC_PI_2.sup),
+            P1_P0 | P1_P1 | P1_Z => Self::with_infsup_raw(f64::atan2(c, b), f64::atan2(d, a)),
 
             // First & second quadrant
             M_P0 | M_Z => Self::with_infsup_raw(0.0, Self::PI.sup),
@@ -175,7 +434,9 @@ impl Interval {
 
             // Fourth quadrant
             P0_N0 => Self::with_infsup_raw(-Self::FRAC_PI_2.sup, 0.0),
-            P0_N1 | P1_N0 | P1_N1 | Z_N1 => Self::with_infsup_raw(f64::atan2(c, a), f64::atan2(d, b)),
+            // Same as P0_P1.
+            P0_N1 => Self::with_infsup_raw(-Self::FRAC_PI_2.sup, f64::atan2(d, b)),
+            P1_N0 | P1_N1 => Self::with_infsup_raw(f64::atan2(c, a), f64::atan2(d, b)),
 
             // Fourth & first quadrant
             P0_M | Z_M => Self::with_infsup_raw(-Self::FRAC_PI_2.sup, Self::FRAC_PI_2.sup),
@@ -192,13 +453,32 @@ impl Interval {
 
             // Y axis
             //Z_M => See above.
-            Z_N0 => -Self::FRAC_PI_2,
-            //Z_N1 => See above.
-            Z_P0 => Self::FRAC_PI_2,
-            //Z_P1 => See above.
+            // f64::atan2 returns π/2 rounded to nearest, which is not an enclosure.
+            Z_N0 | Z_N1 => -Self::FRAC_PI_2,
+            Z_P0 | Z_P1 => Self::FRAC_PI_2,
         }
     }
 
+    /// Converts the Cartesian coordinates $(x, y)$ into the polar coordinates
+    /// and returns `(radius, angle)`, where `radius` is an enclosure of $\sqrt{x^2 + y^2}$
+    /// and `angle` is `y.atan2(x)`.
+    ///
+    /// Note the order of the arguments, which follows [`Interval::atan2`].
+    /// Since the angle is undefined at the origin, `angle` is $∅$ if both `x` and `y` are $\[0, 0\]$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let (r, theta) = Interval::to_polar(const_interval!(4.0), const_interval!(3.0));
+    /// assert_eq!(r, const_interval!(5.0));
+    /// assert!(theta.contains(4.0_f64.atan2(3.0)));
+    /// ```
+    #[must_use]
+    pub fn to_polar(y: Self, x: Self) -> (Self, Self) {
+        (x.hypot(y), y.atan2(x))
+    }
+
     /// Returns the inverse hyperbolic tangent of `self`.
     ///
     /// The domain and the range of the point function are:
@@ -223,6 +503,19 @@ impl Interval {
         Self::with_infsup_raw(f64::atanh(a), f64::atanh(b))
     }
 
+    impl_mono_inc!(
+        /// Returns the cube root of `self`.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain | Range |
+        /// | ------ | ----- |
+        /// | $\R$   | $\R$  |
+        cbrt,
+        f64::cbrt,
+        Self::ENTIRE
+    );
+
     /// Returns the cosine of `self`.
     ///
     /// The domain and the range of the point function are:
@@ -232,13 +525,66 @@ impl Interval {
     /// | $\R$   | $\[-1, 1\]$ |
     #[must_use]
     pub fn cos(self) -> Self {
+        self.cos_with_pi(Self::PI)
+    }
+
+    /// Returns the cosine of `self`, using `pi` as the enclosure of $π$ for the argument reduction.
+    ///
+    /// `pi` must contain $π$, otherwise the result is unspecified and possibly not an enclosure.
+    /// The width of `pi` affects the result only through the period counting,
+    /// which becomes less precise for large arguments as `pi` gets wider.
+    /// Note that [`Interval::PI`], which [`Interval::cos`] uses, is already the tightest enclosure
+    /// with [`f64`] bounds, so a narrower `pi` cannot be passed.
+    ///
+    /// See also: [`Interval::sin_with_pi`].
+    #[must_use]
+    pub fn cos_with_pi(self, pi: Self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        if self.is_entire() {
+            return const_interval!(-1.0, 1.0);
+        }
+
+        self.cos_impl((self / pi).floor(), f64::cos)
+    }
+
+    /// Returns $\cos(π \self)$.
+    ///
+    /// The argument reduction is performed on `self` directly instead of on $π \self$,
+    /// which keeps the result tight for large arguments.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range       |
+    /// | ------ | ----------- |
+    /// | $\R$   | $\[-1, 1\]$ |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(0.5, 1.0).cos_pi(), const_interval!(-1.0, 0.0));
+    /// ```
+    ///
+    /// See also: [`Interval::sin_pi`], [`Interval::tan_pi`].
+    #[must_use]
+    pub fn cos_pi(self) -> Self {
         if self.is_empty() {
             return self;
         }
+        if self.is_entire() {
+            return const_interval!(-1.0, 1.0);
+        }
+
+        self.cos_impl(self.floor(), cos_pi_point)
+    }
 
+    // `q_nowrap` is the enclosure of the indices of the half-periods that `self` spans,
+    // where the cosine is decreasing on the even ones.
+    fn cos_impl(self, q_nowrap: Self, cos: fn(f64) -> f64) -> Self {
         let a = self.inf;
         let b = self.sup;
-        let q_nowrap = (self / Self::PI).floor();
         let qa = q_nowrap.inf;
         let qb = q_nowrap.sup;
         // n and q are valid for small values.
@@ -254,18 +600,18 @@ impl Interval {
         if n == 0.0 {
             if q == 0.0 {
                 // monotonically decreasing
-                Self::with_infsup_raw(f64::cos(b), f64::cos(a))
+                Self::with_infsup_raw(cos(b), cos(a))
             } else {
                 // monotonically increasing
-                Self::with_infsup_raw(f64::cos(a), f64::cos(b))
+                Self::with_infsup_raw(cos(a), cos(b))
             }
         } else if n <= 1.0 {
             if q == 0.0 {
                 // decreasing, then increasing
-                Self::with_infsup_raw(-1.0, f64::cos(a).max(f64::cos(b)))
+                Self::with_infsup_raw(-1.0, cos(a).max(cos(b)))
             } else {
                 // increasing, then decreasing
-                Self::with_infsup_raw(f64::cos(a).min(f64::cos(b)), 1.0)
+                Self::with_infsup_raw(cos(a).min(cos(b)), 1.0)
             }
         } else {
             const_interval!(-1.0, 1.0)
@@ -284,6 +630,9 @@ impl Interval {
         if self.is_empty() {
             return self;
         }
+        if self.is_entire() {
+            return const_interval!(1.0, f64::INFINITY);
+        }
 
         let a = self.inf;
         let b = self.sup;
@@ -305,7 +654,8 @@ impl Interval {
         /// | ------ | -------- |
         /// | $\R$   | $(0, ∞)$ |
         exp,
-        f64::exp
+        f64::exp,
+        const_interval!(0.0, f64::INFINITY)
     );
 	impl_mono_inc!(
         /// Returns `self` raised to the power of $\e$.
@@ -316,7 +666,8 @@ impl Interval {
         /// | ------ | -------- |
         /// | $\R$   | $(0, ∞)$ |
         exp10,
-        libm::exp10
+        libm::exp10,
+        const_interval!(0.0, f64::INFINITY)
     );
     impl_mono_inc!(
         /// Returns `self` raised to the power of 2.
@@ -327,9 +678,168 @@ impl Interval {
         /// | ------ | -------- |
         /// | $\R$   | $(0, ∞)$ |
         exp2,
-        f64::exp2
+        f64::exp2,
+        const_interval!(0.0, f64::INFINITY)
+    );
+    impl_mono_inc!(
+        /// Returns $\e^\self - 1$.
+        ///
+        /// The result is accurate even if `self` is close to zero,
+        /// where $\e^x$ is close to 1 and subtracting 1 from it loses the significant digits.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain | Range     |
+        /// | ------ | --------- |
+        /// | $\R$   | $(-1, ∞)$ |
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use inari_wasm::*;
+        /// let x = const_interval!(1e-20);
+        /// assert!(x.exp_m1().contains(1e-20));
+        /// // e^x rounds to 1, and the difference is 0.
+        /// assert_eq!(x.exp() - const_interval!(1.0), const_interval!(0.0));
+        /// ```
+        exp_m1,
+        f64::exp_m1,
+        const_interval!(-1.0, f64::INFINITY)
     );
 
+    /// Returns the gamma function of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                    | Range          |
+    /// | ------------------------- | -------------- |
+    /// | $\R ∖ \set{0, -1, -2, …}$ | $\R ∖ \set{0}$ |
+    ///
+    /// On $(0, ∞)$, the function attains its minimum $Γ(x_0) ≈ 0.8856$ at $x_0 ≈ 1.4616$,
+    /// which is the lower bound of the result if `self` contains $x_0$.
+    /// The result is $\[-∞, +∞\]$ if `self` contains a pole other than its endpoints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(1.0, 2.0).gamma();
+    /// assert!(x.contains(0.8856031944108887) && x.contains(1.0) && x.sup == 1.0);
+    /// assert_eq!(const_interval!(-0.5, 0.5).gamma(), Interval::ENTIRE);
+    /// assert!(const_interval!(-1.0).gamma().is_empty());
+    /// ```
+    #[must_use]
+    pub fn gamma(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        let a = self.inf;
+        let b = self.sup;
+        if a >= 0.0 {
+            if b == 0.0 {
+                return Self::EMPTY;
+            }
+            // Replace -0.0 with +0.0 since Γ(-0.0) = -∞.
+            return Self::with_infsup_raw(a + 0.0, b).gamma_pos_impl(libm::tgamma, GAMMA_MIN);
+        }
+
+        // Now `self` lies to the left of the pole at 0 or contains it.
+        // [lo, lo + 1] is the closure of the cell between two consecutive poles that contains a.
+        let lo = a.floor();
+        if b > lo + 1.0 {
+            // `self` contains a pole in its interior, where Γ diverges to ±∞ on either side.
+            return Self::ENTIRE;
+        }
+        if a == b {
+            if a == lo {
+                // `self` is a pole.
+                return Self::EMPTY;
+            }
+            let y = libm::tgamma(a);
+            return with_overflowed_bounds(y, y);
+        }
+
+        // On the cell (-n - 1, -n), Γ has the sign (-1)^(n + 1) and |Γ| diverges at both ends.
+        // (ln |Γ|)' = ψ is increasing on the cell, thus |Γ| is decreasing, then increasing.
+        // The signs of ψ at the bounds tell whether `self` lies on one side of the minimum.
+        // Otherwise, the minimum is bounded by |Γ(x)| = π / (|sin(π x)| Γ(1 - x)) ≥ π / (n + 1)!.
+        // A small margin is given to ψ, whose evaluation is not exact.
+        const PSI_TOL: f64 = 1e-8;
+        let n = -lo - 1.0;
+        let is_pole = |x: f64| x == x.floor();
+        let mag = |x: f64| {
+            if is_pole(x) {
+                f64::INFINITY
+            } else {
+                libm::tgamma(x).abs()
+            }
+        };
+        let psi = |x: f64| if is_pole(x) { f64::NAN } else { digamma(x) };
+        let (min, max) = if psi(b) < -PSI_TOL {
+            // |Γ| is decreasing.
+            (mag(b), mag(a))
+        } else if psi(a) > PSI_TOL {
+            // |Γ| is increasing.
+            (mag(a), mag(b))
+        } else {
+            (std::f64::consts::PI / libm::tgamma(n + 2.0), mag(a).max(mag(b)))
+        };
+        if rem_euclid_2(n) == 0.0 {
+            with_overflowed_bounds(-max, -min)
+        } else {
+            with_overflowed_bounds(min, max)
+        }
+    }
+
+    // The implementation of the functions that are decreasing on (0, x_0] and increasing
+    // on [x_0, ∞), where `min` is a lower bound of the minimum, for `self` ⊆ [0, ∞].
+    // The values overflow for large arguments.
+    fn gamma_pos_impl(self, f: fn(f64) -> f64, min: f64) -> Self {
+        let a = self.inf;
+        let b = self.sup;
+        if b <= GAMMA_X0 {
+            // monotonically decreasing
+            with_overflowed_bounds(f(b), f(a))
+        } else if a >= GAMMA_X0 {
+            // monotonically increasing
+            with_overflowed_bounds(f(a), f(b))
+        } else {
+            // decreasing, then increasing
+            with_overflowed_bounds(min, f(a).max(f(b)))
+        }
+    }
+
+    /// Returns the Euclidean norm $\sqrt{\self^2 + \rhs^2}$ of `self` and `rhs`.
+    ///
+    /// Since the point function is even in each argument and increasing in the absolute
+    /// value of each, the bounds are computed from the mignitudes and the magnitudes.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain  | Range     |
+    /// | ------- | --------- |
+    /// | $\R^2$  | $\[0, ∞)$ |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let r = const_interval!(-3.0, 3.0).hypot(const_interval!(0.0, 4.0));
+    /// assert_eq!(r, const_interval!(0.0, 5.0));
+    /// ```
+    #[must_use]
+    pub fn hypot(self, rhs: Self) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::EMPTY;
+        }
+
+        Self::with_infsup_raw(
+            f64::hypot(self.mig(), rhs.mig()),
+            f64::hypot(self.mag(), rhs.mag()),
+        )
+    }
+
     impl_log!(
         /// Returns the natural logarithm of `self`.
         ///
@@ -341,6 +851,66 @@ impl Interval {
         ln,
         f64::ln
     );
+    impl_log!(
+        /// Returns $\ln(1 + \self)$.
+        ///
+        /// The result is accurate even if `self` is close to zero,
+        /// where adding 1 to it before taking the logarithm loses the significant digits.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain    | Range |
+        /// | --------- | ----- |
+        /// | $(-1, ∞)$ | $\R$  |
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use inari_wasm::*;
+        /// let x = const_interval!(1e-20);
+        /// assert!(x.ln_1p().contains(1e-20));
+        /// // 1 + x is not representable, and the enclosure of its logarithm is much wider.
+        /// assert!((const_interval!(1.0) + x).ln().wid() > 1e-16);
+        /// assert!(x.ln_1p().wid() < 1e-30);
+        /// assert_eq!(const_interval!(0.0).ln_1p(), const_interval!(0.0));
+        /// ```
+        ln_1p,
+        f64::ln_1p,
+        -1.0
+    );
+
+    /// Returns the natural logarithm of the gamma function of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain   | Range                |
+    /// | -------- | -------------------- |
+    /// | $(0, ∞)$ | $\[\ln Γ(x_0), ∞)$ |
+    ///
+    /// where $x_0 ≈ 1.4616$ is the point at which $Γ$ attains its minimum on $(0, ∞)$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(1.0, 2.0).ln_gamma().sup, 0.0);
+    /// assert!(const_interval!(-2.0, 0.0).ln_gamma().is_empty());
+    /// ```
+    ///
+    /// See also: [`Interval::gamma`].
+    #[must_use]
+    pub fn ln_gamma(self) -> Self {
+        // See the comment in atanh_impl.
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let x = self.intersection(DOM);
+
+        if x.is_empty() || x.sup <= 0.0 {
+            return Self::EMPTY;
+        }
+
+        // Replace -0.0 with +0.0.
+        Self::with_infsup_raw(x.inf + 0.0, x.sup).gamma_pos_impl(libm::lgamma, LN_GAMMA_MIN)
+    }
     impl_log!(
         /// Returns the base-10 logarithm of `self`.
         ///
@@ -428,6 +998,57 @@ impl Interval {
         }
     }
 
+    /// Returns `self` raised to the power of `c`, where `c` is a positive constant.
+    ///
+    /// This is equivalent to [`self.pow(interval!(c, c).unwrap())`](`Interval::pow`),
+    /// but since $x^c$ is monotonically increasing on $\[0, ∞)$ for $c > 0$,
+    /// the result is computed directly from the bounds of `self` ∩ $\[0, ∞)$
+    /// without the case analysis of [`Interval::pow`].
+    /// If `c` is zero or negative, this falls back to [`Interval::pow`].
+    ///
+    /// The bounds are rounded outward by one ulp, unless they are found to be exact,
+    /// which is the case for example if `c` is a small integer or half-integer
+    /// and the power is representable.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain    | Range     |
+    /// | --------- | --------- |
+    /// | $\[0, ∞)$ | $\[0, ∞)$ |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(4.0, 9.0).powf_pos(1.5), const_interval!(8.0, 27.0));
+    /// assert_eq!(const_interval!(-1.0, 4.0).powf_pos(1.5), const_interval!(0.0, 8.0));
+    /// let x = const_interval!(2.0).powf_pos(0.5);
+    /// assert!(x.inf < std::f64::consts::SQRT_2 && std::f64::consts::SQRT_2 < x.sup);
+    /// ```
+    #[must_use]
+    pub fn powf_pos(self, c: f64) -> Self {
+        if c.is_nan() {
+            return Self::EMPTY;
+        }
+        if c <= 0.0 {
+            return self.pow(Self::with_infsup_raw(c, c));
+        }
+
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let x = self.intersection(DOM);
+
+        if x.is_empty() {
+            return x;
+        }
+
+        // f64::powf is not correctly rounded, but its error is below one ulp.
+        let (a, b) = (x.inf, x.sup);
+        let (ra, rb) = (f64::powf(a, c), f64::powf(b, c));
+        let inf = if powf_is_exact(a, c, ra) { ra } else { ra.next_down().max(0.0) };
+        let sup = if powf_is_exact(b, c, rb) { rb } else { rb.next_up() };
+        Self::with_infsup_raw(inf, sup)
+    }
+
     /// Returns `self` raised to the power of `rhs`.
     ///
     /// The point functions are indexed by $n$, and are defined as follows:
@@ -455,9 +1076,18 @@ impl Interval {
             return self;
         }
 
+        if rhs == 0 {
+            // x^0 = 1 for every x, including 0 and unbounded intervals.
+            return const_interval!(1.0);
+        }
+
         let mut a = self.inf;
         let mut b = self.sup;
+        let n = rhs.unsigned_abs();
 
+        // Each bound is rounded outward. For n < 0, x^n = 1 / x^(-n) is decreasing in x^(-n)
+        // on either side of zero, so the lower bound is 1 over the upper bound of x^(-n)
+        // and vice versa.
         #[allow(clippy::collapsible_else_if, clippy::collapsible_if)]
         if rhs < 0 {
 			if a == 0.0 && b == 0.0 {
@@ -466,7 +1096,7 @@ impl Interval {
 
             if rhs % 2 == 0 {
                 let abs = self.abs();
-				Self::with_infsup_raw(f64::powi(abs.sup, rhs), f64::powi(abs.inf, rhs))
+				with_overflowed_bounds(div_down(1.0, pow_up(abs.sup, n)), div_up(1.0, pow_down(abs.inf, n)))
             } else {
                 if a < 0.0 && b > 0.0 {
                     Self::ENTIRE
@@ -477,35 +1107,161 @@ impl Interval {
                     if b == 0.0 {
                         b = -0.0; // [a, 0]
                     }
-                    Self::with_infsup_raw(f64::powi(b, rhs), f64::powi(a, rhs))
+                    with_overflowed_bounds(div_down(1.0, pow_up(b, n)), div_up(1.0, pow_down(a, n)))
                 }
             }
         } else {
             if rhs % 2 == 0 {
-                let abs = self.abs();
-				Self::with_infsup_raw(f64::powi(abs.inf, rhs), f64::powi(abs.sup, rhs))
+                if a >= 0.0 {
+                    with_overflowed_bounds(pow_down(a, n), pow_up(b, n))
+                } else if b <= 0.0 {
+                    with_overflowed_bounds(pow_down(b, n), pow_up(a, n))
+                } else {
+                    // [0, max(|a|, |b|)^n]
+                    with_overflowed_bounds(0.0, pow_up(f64::max(-a, b), n))
+                }
             } else {
-				Self::with_infsup_raw(f64::powi(a, rhs), f64::powi(b, rhs))
+				with_overflowed_bounds(pow_down(a, n), pow_up(b, n))
             }
         }
     }
 
-    /// Returns the sine of `self`.
+    /// Returns the `n`-th root of `self`, the inverse of [`Interval::powi`].
     ///
-    /// The domain and the range of the point function are:
+    /// The point function is defined as follows:
     ///
-    /// | Domain | Range       |
-    /// | ------ | ----------- |
-    /// | $\R$   | $\[-1, 1\]$ |
+    /// $$
+    /// \sqrt\[n\]{x} = \begin{cases}
+    ///   y \text{ such that } y^n = x \text{ and } y ≥ 0 & \for n > 0, \text{even}, \\\\
+    ///   y \text{ such that } y^n = x                    & \for n > 0, \text{odd}, \\\\
+    ///   1 / \sqrt\[-n\]{x}                              & \for n < 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The result is $∅$ for $n = 0$.
+    ///
+    /// The domains and the ranges of the point functions are:
+    ///
+    /// |                | Domain        | Range         |
+    /// | -------------- | ------------- | ------------- |
+    /// | $n > 0$, odd   | $\R$          | $\R$          |
+    /// | $n > 0$, even  | $\[0, ∞)$     | $\[0, ∞)$     |
+    /// | $n < 0$, odd   | $\R ∖ \set 0$ | $\R ∖ \set 0$ |
+    /// | $n < 0$, even  | $(0, ∞)$      | $(0, ∞)$      |
+    ///
+    /// The bounds of the result are computed by [`libm::pow`] with the exponent $1/n$,
+    /// and are moved outward to the adjacent [`f64`] numbers unless they are exact roots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(8.0, 27.0).rootn(3), const_interval!(2.0, 3.0));
+    /// assert_eq!(const_interval!(-8.0, -1.0).rootn(3), const_interval!(-2.0, -1.0));
+    /// assert_eq!(const_interval!(-4.0, 16.0).rootn(4), const_interval!(0.0, 2.0));
+    /// assert_eq!(const_interval!(-16.0, -1.0).rootn(4), Interval::EMPTY);
+    /// assert_eq!(const_interval!(4.0, 16.0).rootn(-2), const_interval!(0.25, 0.5));
+    /// assert_eq!(const_interval!(-8.0, -1.0).rootn(-3), const_interval!(-1.0, -0.5));
+    /// assert_eq!(const_interval!(2.0).rootn(0), Interval::EMPTY);
+    /// ```
     #[must_use]
-    pub fn sin(self) -> Self {
+    pub fn rootn(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::EMPTY;
+        }
+
+        let m = n.unsigned_abs();
+        let x = if m.is_multiple_of(2) {
+            self.intersection(const_interval!(0.0, f64::INFINITY))
+        } else {
+            self
+        };
+        if x.is_empty() {
+            return x;
+        }
+
+        // For a negative bound, the root is odd, so the bounds of the root of |bound| are swapped.
+        let (a, b) = (x.inf, x.sup);
+        let inf = if a < 0.0 { -root_bounds(-a, m).1 } else { root_bounds(a, m).0 };
+        let sup = if b < 0.0 { -root_bounds(-b, m).0 } else { root_bounds(b, m).1 };
+        let y = Self::with_infsup_raw(inf, sup);
+
+        if n < 0 {
+            y.recip()
+        } else {
+            y
+        }
+    }
+
+    /// Returns the sine of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range       |
+    /// | ------ | ----------- |
+    /// | $\R$   | $\[-1, 1\]$ |
+    #[must_use]
+    pub fn sin(self) -> Self {
+        self.sin_with_pi(Self::PI)
+    }
+
+    /// Returns the sine of `self`, using `pi` as the enclosure of $π$ for the argument reduction.
+    ///
+    /// `pi` must contain $π$, otherwise the result is unspecified and possibly not an enclosure.
+    /// See [`Interval::cos_with_pi`] for details.
+    #[must_use]
+    pub fn sin_with_pi(self, pi: Self) -> Self {
         if self.is_empty() {
             return self;
         }
+        if self.is_entire() {
+            return const_interval!(-1.0, 1.0);
+        }
 
+        // Halving is exact.
+        let frac_pi_2 = pi * const_interval!(0.5);
+        self.sin_impl((self / frac_pi_2).floor(), f64::sin)
+    }
+
+    /// Returns $\sin(π \self)$.
+    ///
+    /// The argument reduction is performed on `self` directly instead of on $π \self$,
+    /// which keeps the result tight for large arguments.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range       |
+    /// | ------ | ----------- |
+    /// | $\R$   | $\[-1, 1\]$ |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(0.0, 0.5).sin_pi(), const_interval!(0.0, 1.0));
+    /// let x = const_interval!(1e15, 1e15 + 0.5);
+    /// assert_eq!(x.sin_pi(), const_interval!(0.0, 1.0));
+    /// ```
+    ///
+    /// See also: [`Interval::cos_pi`], [`Interval::tan_pi`].
+    #[must_use]
+    pub fn sin_pi(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        if self.is_entire() {
+            return const_interval!(-1.0, 1.0);
+        }
+
+        // Doubling is exact unless it overflows, in which case the result is [-1, 1].
+        self.sin_impl((self * const_interval!(2.0)).floor(), sin_pi_point)
+    }
+
+    // `q_nowrap` is the enclosure of the indices of the quarter-periods that `self` spans,
+    // where the sine is increasing on the ones congruent to 0 or 3 modulo 4.
+    fn sin_impl(self, q_nowrap: Self, sin: fn(f64) -> f64) -> Self {
         let a = self.inf;
         let b = self.sup;
-        let q_nowrap = (self / Self::FRAC_PI_2).floor();
         let qa = q_nowrap.inf;
         let qb = q_nowrap.sup;
         let n = if a == b { 0.0 } else { qb - qa };
@@ -513,21 +1269,29 @@ impl Interval {
 
         if q == 0.0 && n < 1.0 || q == 3.0 && n < 2.0 {
             // monotonically increasing
-            Self::with_infsup_raw(f64::sin(a), f64::sin(b))
+            Self::with_infsup_raw(sin(a), sin(b))
         } else if q == 1.0 && n < 2.0 || q == 2.0 && n < 1.0 {
             // monotonically decreasing
-            Self::with_infsup_raw(f64::sin(b), f64::sin(a))
+            Self::with_infsup_raw(sin(b), sin(a))
         } else if q == 0.0 && n < 3.0 || q == 3.0 && n < 4.0 {
             // increasing, then decreasing
-            Self::with_infsup_raw(f64::sin(a).min(f64::sin(b)), 1.0)
+            Self::with_infsup_raw(sin(a).min(sin(b)), 1.0)
         } else if q == 1.0 && n < 4.0 || q == 2.0 && n < 3.0 {
             // decreasing, then increasing
-            Self::with_infsup_raw(-1.0, f64::sin(a).max(f64::sin(b)))
+            Self::with_infsup_raw(-1.0, sin(a).max(sin(b)))
         } else {
             const_interval!(-1.0, 1.0)
         }
     }
 
+    /// Returns the sine and the cosine of `self` as a pair `(self.sin(), self.cos())`.
+    ///
+    /// See also: [`Interval::sin`], [`Interval::cos`].
+    #[must_use]
+    pub fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
     impl_mono_inc!(
         /// Returns the hyperbolic sine of `self`.
         ///
@@ -537,9 +1301,123 @@ impl Interval {
         /// | ------ | ----- |
         /// | $\R$   | $\R$  |
         sinh,
-        f64::sinh
+        f64::sinh,
+        Self::ENTIRE
     );
 
+    /// Returns the principal square root of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain    | Range     |
+    /// | --------- | --------- |
+    /// | $\[0, ∞)$ | $\[0, ∞)$ |
+    ///
+    /// Unlike the transcendental functions, which are computed by libm with errors up to
+    /// a few ulps, [`f64::sqrt`] is correctly rounded as required by IEEE 754,
+    /// and the rounding error can be detected with a fused multiply-add.
+    /// Thus the bounds of the result are rounded outward to the adjacent [`f64`] numbers
+    /// of the exact square roots, and they are exact whenever the square roots are representable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(4.0, 9.0).sqrt(), const_interval!(2.0, 3.0));
+    /// assert_eq!(const_interval!(-1.0, 4.0).sqrt(), const_interval!(0.0, 2.0));
+    /// assert_eq!(const_interval!(-4.0, -1.0).sqrt(), Interval::EMPTY);
+    /// assert_eq!(Interval::EMPTY.sqrt(), Interval::EMPTY);
+    ///
+    /// let x = const_interval!(2.0).sqrt();
+    /// assert!(x.contains(std::f64::consts::SQRT_2));
+    /// assert!(x.inf * x.inf <= 2.0 && 2.0 <= x.sup * x.sup);
+    /// ```
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let x = self.intersection(DOM);
+
+        if x.is_empty() {
+            return x;
+        }
+
+        // The sign of r^2 - a tells the direction of the rounding error of r = sqrt(a).
+        // It is NaN for a = +∞, in which case r = +∞ is exact.
+        let (a, b) = (x.inf, x.sup);
+        let (ra, rb) = (f64::sqrt(a), f64::sqrt(b));
+        let inf = if ra.mul_add(ra, -a) > 0.0 { ra.next_down() } else { ra };
+        let sup = if rb.mul_add(rb, -b) < 0.0 { rb.next_up() } else { rb };
+        Self::with_infsup_raw(inf, sup)
+    }
+
+    /// Returns the square of `self`, which is $\[\operatorname{mig}(\self)^2, \operatorname{mag}(\self)^2\]$.
+    ///
+    /// The bounds are rounded outward as in multiplication, but unlike `self * self`,
+    /// the result never contains negative numbers.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range     |
+    /// | ------ | --------- |
+    /// | $\R$   | $\[0, ∞)$ |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-3.0, 2.0).sqr(), const_interval!(0.0, 9.0));
+    /// assert_eq!(const_interval!(2.0, 3.0).sqr(), const_interval!(4.0, 9.0));
+    /// assert_eq!(const_interval!(-3.0, -2.0).sqr(), const_interval!(4.0, 9.0));
+    /// assert_eq!(Interval::EMPTY.sqr(), Interval::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn sqr(self) -> Self {
+        let (a, b) = (self.inf, self.sup);
+
+        use IntervalClass::*;
+        match self.classify() {
+            E => Self::EMPTY,
+            // [a², b²]
+            P0 | P1 | Z => with_overflowed_bounds(mul_down(a, a), mul_up(b, b)),
+            // [b², a²]
+            N0 | N1 => with_overflowed_bounds(mul_down(b, b), mul_up(a, a)),
+            // [0, max(-a, b)²]
+            M => {
+                let m = f64::max(-a, b);
+                with_overflowed_bounds(0.0, mul_up(m, m))
+            }
+        }
+    }
+
+    /// Returns `self.sqrt()`, paired with how `self` lies relative to the domain $\[0, ∞)$.
+    ///
+    /// This distinguishes, for example, a discriminant that dips slightly below zero
+    /// due to overestimation ([`DomainStatus::ClippedLow`]) from one that is definitely negative
+    /// ([`DomainStatus::Empty`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(1.0, 4.0).sqrt_status(), (const_interval!(1.0, 2.0), DomainStatus::Interior));
+    /// assert_eq!(const_interval!(-1e-10, 4.0).sqrt_status(), (const_interval!(0.0, 2.0), DomainStatus::ClippedLow));
+    /// assert_eq!(const_interval!(-4.0, -1.0).sqrt_status(), (Interval::EMPTY, DomainStatus::Empty));
+    /// ```
+    #[must_use]
+    pub fn sqrt_status(self) -> (Self, DomainStatus) {
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let (x, clipped) = self.clip_to(DOM);
+
+        let status = if x.is_empty() {
+            DomainStatus::Empty
+        } else if clipped {
+            DomainStatus::ClippedLow
+        } else {
+            DomainStatus::Interior
+        };
+        (x.sqrt(), status)
+    }
+
     /// Returns the tangent of `self`.
     ///
     /// The domain and the range of the point function are:
@@ -552,20 +1430,72 @@ impl Interval {
         if self.is_empty() {
             return self;
         }
+        if self.is_entire() {
+            return Self::ENTIRE;
+        }
+
+        let q_nowrap = (self / Self::FRAC_PI_2).floor();
+        let qb = q_nowrap.sup;
+        let cont = qb != f64::INFINITY
+            && self.sup <= (Self::with_infsup_raw(qb, qb) * Self::FRAC_PI_2).inf;
+        self.tan_impl(q_nowrap, cont, f64::tan)
+    }
 
+    /// Returns $\tan(π \self)$.
+    ///
+    /// The argument reduction is performed on `self` directly instead of on $π \self$,
+    /// which keeps the result tight for large arguments.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                        | Range |
+    /// | ----------------------------- | ----- |
+    /// | $\R ∖ \set{n + 1/2 ∣ n ∈ \Z}$ | $\R$  |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-0.25, 0.25).tan_pi(), const_interval!(-1.0, 1.0));
+    /// assert_eq!(const_interval!(0.25, 0.75).tan_pi(), Interval::ENTIRE);
+    /// ```
+    ///
+    /// See also: [`Interval::sin_pi`], [`Interval::cos_pi`].
+    #[must_use]
+    pub fn tan_pi(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        if self.is_entire() {
+            return Self::ENTIRE;
+        }
+
+        let q_nowrap = (self * const_interval!(2.0)).floor();
+        let qa = q_nowrap.inf;
+        if self.inf == self.sup && 2.0 * self.inf == qa && rem_euclid_2(qa) == 1.0 {
+            // `self` is a pole.
+            return Self::EMPTY;
+        }
+        // The reduction is exact, thus `self` crosses a pole whenever it spans
+        // two quarter-periods starting from an even one.
+        self.tan_impl(q_nowrap, false, tan_pi_point)
+    }
+
+    // `q_nowrap` is the enclosure of the indices of the quarter-periods that `self` spans,
+    // where the poles lie at the ends of the even ones.
+    // `cont` tells whether `self.sup` lies before the pole that the overestimated `q_nowrap.sup`
+    // suggests `self` has crossed.
+    fn tan_impl(self, q_nowrap: Self, cont: bool, tan: fn(f64) -> f64) -> Self {
         let a = self.inf;
         let b = self.sup;
-        let q_nowrap = (self / Self::FRAC_PI_2).floor();
         let qa = q_nowrap.inf;
         let qb = q_nowrap.sup;
         let n = if a == b { 0.0 } else { qb - qa };
         let q = rem_euclid_2(qa);
 
-        let cont =
-            qb != f64::INFINITY && b <= (Self::with_infsup_raw(qb, qb) * Self::FRAC_PI_2).inf;
         if q == 0.0 && (n < 1.0 || n == 1.0 && cont) || q == 1.0 && (n < 2.0 || n == 2.0 && cont) {
             // In case of overflow, the decoration must be corrected by the caller.
-            Self::with_infsup_raw(f64::tan(a), f64::tan(b))
+            Self::with_infsup_raw(tan(a), tan(b))
         } else {
             Self::ENTIRE
         }
@@ -580,16 +1510,856 @@ impl Interval {
         /// | ------ | --------- |
         /// | $\R$   | $(-1, 1)$ |
         tanh,
-        f64::tanh
+        f64::tanh,
+        const_interval!(-1.0, 1.0)
     );
 }
 
 
+impl Interval {
+    /// Converts the amplitude ratio `self` into decibels, $20 \log_{10} x$.
+    ///
+    /// The factor 20 is for amplitude (field) quantities such as voltage or sound pressure;
+    /// for power quantities, which are proportional to the square of amplitudes,
+    /// use `self.sqrt().to_db()` or $10 \log_{10} x$ directly.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain   | Range |
+    /// | -------- | ----- |
+    /// | $(0, ∞)$ | $\R$  |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(1.0, 10.0).to_db(), const_interval!(0.0, 20.0));
+    /// assert!(const_interval!(-1.0, 0.0).to_db().is_empty());
+    /// ```
+    ///
+    /// See also: [`Interval::from_db`].
+    #[must_use]
+    pub fn to_db(self) -> Self {
+        const_interval!(20.0) * self.log10()
+    }
+
+    /// Converts the decibel value `self` into the amplitude ratio, $10^{x/20}$.
+    ///
+    /// This is the inverse of [`Interval::to_db`].
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range    |
+    /// | ------ | -------- |
+    /// | $\R$   | $(0, ∞)$ |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(0.0, 20.0).from_db(), const_interval!(1.0, 10.0));
+    /// ```
+    #[must_use]
+    pub fn from_db(self) -> Self {
+        (self / const_interval!(20.0)).exp10()
+    }
+
+    /// Converts the angle `self` from degrees into radians, $x π / 180$.
+    ///
+    /// The result is computed with the tightest enclosure of $π / 180$,
+    /// [`Interval::FRAC_PI_180`], thus it encloses the exact conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(const_interval!(180.0).to_radians().contains(std::f64::consts::PI));
+    /// assert!(Interval::PI.subset(const_interval!(180.0).to_radians()));
+    /// ```
+    ///
+    /// See also: [`Interval::to_degrees`].
+    #[must_use]
+    pub fn to_radians(self) -> Self {
+        self * Self::FRAC_PI_180
+    }
+
+    /// Converts the angle `self` from radians into degrees, $180 x / π$.
+    ///
+    /// The result is computed with the tightest enclosure of $180 / π$,
+    /// [`Interval::FRAC_180_PI`], thus it encloses the exact conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert!(Interval::PI.to_degrees().contains(180.0));
+    /// ```
+    ///
+    /// See also: [`Interval::to_radians`].
+    #[must_use]
+    pub fn to_degrees(self) -> Self {
+        self * Self::FRAC_180_PI
+    }
+}
+
+/// How the argument of a function lies relative to the domain of the function,
+/// returned by [`Interval::sqrt_status`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DomainStatus {
+    /// The argument is nonempty and is a subset of the domain.
+    Interior,
+    /// The argument is partly below the domain, and that part has been clipped off.
+    ClippedLow,
+    /// The argument is empty or disjoint from the domain, and thus the result is empty.
+    Empty,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use crate::{test_util::sample_f64s, *};
     use Interval as I;
 
+    // Checks that `f(x)` contains `f_real(t)` for sampled points `t` in `x`.
+    fn check_samples(f: fn(I) -> I, f_real: fn(f64) -> f64, x: I) {
+        const N: usize = 200;
+        let y = f(x);
+        let (a, b) = (x.inf, x.sup);
+        for i in 0..=N {
+            let t = (a + (b - a) * (i as f64 / N as f64)).clamp(a, b);
+            let z = f_real(t);
+            assert!(
+                z.is_nan() || y.contains(z) || z.is_infinite() && y.sup - y.inf == f64::INFINITY,
+                "f({}) = {} is not contained in f({}) = {}",
+                t,
+                z,
+                x,
+                y
+            );
+        }
+    }
+
+    #[test]
+    fn trig_samples() {
+        use std::f64::consts::FRAC_PI_4;
+
+        let mut centers = vec![];
+        for k in -16..=16 {
+            // Multiples of π/4 and points near them.
+            let c = k as f64 * FRAC_PI_4;
+            centers.extend([c, c - 1e-3, c + 1e-3, c + 0.3]);
+        }
+        centers.extend([1e3, -1e3, 123456.789, 1e6 + 0.5]);
+
+        for c in centers {
+            for w in [0.0, 1e-10, 1e-3, 0.1, 0.5, 1.0, 2.0, 3.5, 7.0] {
+                let x = interval!(c - w, c + w).unwrap();
+                check_samples(I::sin, f64::sin, x);
+                check_samples(I::cos, f64::cos, x);
+                check_samples(I::tan, f64::tan, x);
+            }
+        }
+    }
+
+    #[test]
+    fn powf_pos() {
+        let xs = [
+            const_interval!(0.0, 0.5),
+            const_interval!(0.25, 4.0),
+            const_interval!(2.0, 3.0),
+            const_interval!(-2.0, 3.0),
+            const_interval!(0.0, f64::INFINITY),
+            I::ENTIRE,
+        ];
+        for x in xs {
+            for c in [1.5, 2.0 / 3.0, 0.1, 7.0] {
+                let y = x.powf_pos(c);
+                assert!(x.pow(interval!(c).unwrap()).subset(y));
+                // A wider exponent gives a wider result.
+                assert!(y.subset(x.pow(interval!(c * 0.99, c * 1.01).unwrap())));
+            }
+            assert_eq!(x.powf_pos(-1.5), x.pow(const_interval!(-1.5)));
+            assert_eq!(x.powf_pos(0.0), x.pow(const_interval!(0.0)));
+        }
+
+        assert_eq!(const_interval!(1.0, 4.0).powf_pos(1.5), const_interval!(1.0, 8.0));
+        assert_eq!(const_interval!(0.25, 9.0).powf_pos(0.5), const_interval!(0.5, 3.0));
+        assert_eq!(const_interval!(2.0, 3.0).powf_pos(7.0), const_interval!(128.0, 2187.0));
+
+        // √2 lies strictly inside, which is checked exactly with FMA.
+        let y = const_interval!(2.0).powf_pos(0.5);
+        assert!(y.inf.mul_add(y.inf, -2.0) < 0.0 && y.sup.mul_add(y.sup, -2.0) > 0.0);
+        // 2^0.1 is irrational.
+        let y = const_interval!(2.0).powf_pos(0.1);
+        assert!(y.inf < y.sup && y.contains(2.0f64.powf(0.1)));
+        assert_eq!(const_interval!(0.0, 1.0).powf_pos(0.1), const_interval!(0.0, 1.0));
+        assert!(const_interval!(-2.0, -1.0).powf_pos(1.5).is_empty());
+        assert!(I::EMPTY.powf_pos(1.5).is_empty());
+        assert!(I::PI.powf_pos(f64::NAN).is_empty());
+    }
+
+    #[test]
+    fn powi_zero_base() {
+        assert_eq!(const_interval!(0.0, 2.0).powi(2), const_interval!(0.0, 4.0));
+        assert_eq!(const_interval!(0.0, 2.0).powi(3), const_interval!(0.0, 8.0));
+        assert_eq!(const_interval!(-2.0, 0.0).powi(2), const_interval!(0.0, 4.0));
+        assert_eq!(const_interval!(-2.0, 0.0).powi(3), const_interval!(-8.0, 0.0));
+        assert_eq!(const_interval!(-2.0, 3.0).powi(2), const_interval!(0.0, 9.0));
+        assert_eq!(const_interval!(-2.0, 3.0).powi(3), const_interval!(-8.0, 27.0));
+        assert_eq!(const_interval!(0.0).powi(2), const_interval!(0.0));
+        assert_eq!(const_interval!(0.0).powi(3), const_interval!(0.0));
+
+        // 0^0 = 1
+        assert_eq!(const_interval!(0.0).powi(0), const_interval!(1.0));
+        assert_eq!(const_interval!(0.0, 2.0).powi(0), const_interval!(1.0));
+        assert_eq!(const_interval!(-2.0, 0.0).powi(0), const_interval!(1.0));
+    }
+
+    #[test]
+    fn powi_zero_exponent() {
+        let xs = [
+            I::ENTIRE,
+            const_interval!(0.0),
+            const_interval!(-5.0, 5.0),
+            const_interval!(0.0, f64::INFINITY),
+            const_interval!(f64::NEG_INFINITY, -1.0),
+            const_interval!(f64::MAX),
+            const_interval!(-3.0, -2.0),
+        ];
+        for x in xs {
+            assert_eq!(x.powi(0), const_interval!(1.0));
+        }
+        assert!(I::EMPTY.powi(0).is_empty());
+    }
+
+    #[test]
+    fn powi_exact() {
+        // Powers of ±2^k and ±3 × 2^k are exact, and so are the reciprocals of ±2^k.
+        for k in -20..=20 {
+            for m in [1.0, 3.0] {
+                for sign in [1.0, -1.0] {
+                    let t = sign * m * 2.0f64.powi(k);
+                    let mut z = 1.0;
+                    for n in 1..=10 {
+                        z *= t;
+                        let y = interval!(t, t).unwrap();
+                        assert_eq!(y.powi(n), interval!(z, z).unwrap(), "{}^{}", t, n);
+                        if m == 1.0 {
+                            assert_eq!(y.powi(-n), interval!(1.0 / z, 1.0 / z).unwrap(), "{}^-{}", t, n);
+                        } else {
+                            // 1/z is not representable, and is strictly enclosed.
+                            let y = y.powi(-n);
+                            let s = z.signum();
+                            assert!(s * y.inf.mul_add(z, -1.0) < 0.0 && s * y.sup.mul_add(z, -1.0) > 0.0);
+                        }
+                    }
+                }
+            }
+        }
+
+        // The cube of the f64 number 0.1 lies strictly between these numbers.
+        let y = const_interval!(0.1).powi(3);
+        assert!(y.inf <= 0.001 && 0.0010000000000000002 <= y.sup);
+        assert!(y.sup - y.inf <= 4.0 * f64::EPSILON * 0.001);
+        let y = const_interval!(-0.1).powi(3);
+        assert!(y.inf <= -0.0010000000000000002 && -0.001 <= y.sup);
+    }
+
+    // Returns t^n as an unevaluated sum hi + lo of double-double arithmetic,
+    // which is exact if every power of t up to t^|n| is representable.
+    fn powi_dd(t: f64, n: i32) -> (f64, f64) {
+        let mul = |(h, l): (f64, f64), t: f64| {
+            let p = h * t;
+            let e = h.mul_add(t, -p) + l * t;
+            let s = p + e;
+            (s, e - (s - p))
+        };
+        let (mut hi, mut lo) = (1.0, 0.0);
+        for _ in 0..n.unsigned_abs() {
+            (hi, lo) = mul((hi, lo), t);
+        }
+        if n < 0 {
+            // 1 / (hi + lo) = q + (1 - q hi - q lo) / hi
+            let q = 1.0 / hi;
+            let r = ((-q).mul_add(hi, 1.0) - q * lo) / hi;
+            let s = q + r;
+            (hi, lo) = (s, r - (s - q));
+        }
+        (hi, lo)
+    }
+
+    #[test]
+    fn powi_samples() {
+        // x.powi(n) ∋ t^n for sampled t ∈ x, over all sign classes of x.
+        let mut us = sample_f64s(0x9e3779b97f4a7c15, 100000);
+        let mut next = || us.next().unwrap();
+
+        for _ in 0..20000 {
+            let scale = [1e-200, 1e-5, 1.0, 3.0, 1e5, 1e100][(next() * 6.0) as usize];
+            let mut a = (next() * 2.0 - 1.0) * scale;
+            let mut b = (next() * 2.0 - 1.0) * scale;
+            if a > b {
+                std::mem::swap(&mut a, &mut b);
+            }
+            match (next() * 5.0) as u32 {
+                0 => (a, b) = (0.0, b.abs()),
+                1 => (a, b) = (-a.abs(), 0.0),
+                2 => (a, b) = (a.abs().min(b.abs()), a.abs().max(b.abs())),
+                3 => (a, b) = (-a.abs().max(b.abs()), -a.abs().min(b.abs())),
+                _ => (),
+            }
+            let x = interval!(a, b).unwrap();
+            let n = (next() * 21.0) as i32 - 10;
+            let y = x.powi(n);
+            assert!(y.inf <= y.sup, "{}.powi({}) = [{}, {}]", x, n, y.inf, y.sup);
+
+            // t^n is compared with its double-double approximation hi + lo, whose relative error
+            // is far below that of `lo`, except in the ranges where `lo` can underflow.
+            for i in 0..=16 {
+                let t = (a + (b - a) * (i as f64 / 16.0)).clamp(a, b);
+                if t == 0.0 && n < 0 {
+                    continue;
+                }
+                let (hi, lo) = powi_dd(t, n);
+                if hi.is_finite() && hi.abs() >= 1e-290 {
+                    assert!(y.inf < hi || y.inf == hi && lo >= 0.0, "{}^{} < {}.powi({})", t, n, x, n);
+                    assert!(hi < y.sup || hi == y.sup && lo <= 0.0, "{}^{} > {}.powi({})", t, n, x, n);
+                }
+            }
+
+            if scale <= 1e5 {
+                assert!(x.powi(2).subset(x * x));
+                assert!(x.powi(3).subset(x * x * x));
+            }
+        }
+
+        // Both bounds overflow.
+        const MAX: f64 = f64::MAX;
+        assert_eq!(const_interval!(1e100, 1e200).powi(5), interval!(MAX, f64::INFINITY).unwrap());
+        assert_eq!(const_interval!(-1e200, -1e100).powi(5), interval!(f64::NEG_INFINITY, -MAX).unwrap());
+        assert_eq!(const_interval!(-1e200, -1e100).powi(4), interval!(MAX, f64::INFINITY).unwrap());
+        assert_eq!(const_interval!(1e-200, 1e-100).powi(-5), interval!(MAX, f64::INFINITY).unwrap());
+        assert_eq!(const_interval!(-1e-200, -0.0).powi(-5), interval!(f64::NEG_INFINITY, -MAX).unwrap());
+        assert_eq!(const_interval!(1e200, 1e300).sqr(), interval!(MAX, f64::INFINITY).unwrap());
+    }
+
+    #[test]
+    fn rootn() {
+        const INF: f64 = f64::INFINITY;
+        for n in 1..=9 {
+            for k in 0..=20 {
+                // Perfect powers have exact roots.
+                let x = interval!(f64::powi(k as f64, n)).unwrap();
+                assert_eq!(x.rootn(n), interval!(k as f64).unwrap(), "{}.rootn({})", x, n);
+                if n % 2 == 1 {
+                    assert_eq!((-x).rootn(n), interval!(-(k as f64)).unwrap(), "{}.rootn({})", -x, n);
+                }
+            }
+
+            for t in [5e-324, 1e-310, 1e-300, 1e-5, 0.1, 2.0, 3.0, 10.0, 1e10, 1e300, f64::MAX] {
+                let y = interval!(t).unwrap().rootn(n);
+                // The directed powers that verify the bounds are a few ulps loose.
+                assert!(y.inf <= y.sup && y.wid() <= 4.0 * f64::EPSILON * y.sup, "{}.rootn({}) = {}", t, n, y);
+                // The bounds are checked with the double-double powers, which are exact enough.
+                // The power of the upper bound of `MAX.rootn(n)` overflows, and is then NaN.
+                let ((p, e), (q, f)) = (powi_dd(y.inf, n), powi_dd(y.sup, n));
+                assert!(p < t || p == t && e <= 0.0, "{}.rootn({}) = {}", t, n, y);
+                assert!(q.is_nan() || t < q || t == q && f >= 0.0, "{}.rootn({}) = {}", t, n, y);
+            }
+
+            assert_eq!(I::ENTIRE.rootn(n), if n % 2 == 0 { const_interval!(0.0, INF) } else { I::ENTIRE });
+            assert!(I::EMPTY.rootn(n).is_empty());
+            assert!(I::EMPTY.rootn(-n).is_empty());
+        }
+
+        assert_eq!(const_interval!(-1.0, 4.0).rootn(2), const_interval!(0.0, 2.0));
+        assert_eq!(const_interval!(-1.0, 4.0).rootn(-2), const_interval!(0.5, INF));
+        assert_eq!(const_interval!(-8.0, 27.0).rootn(-3), I::ENTIRE);
+        assert!(const_interval!(-0.0, 0.0).rootn(-2).is_empty());
+        assert!(const_interval!(1.0, 2.0).rootn(0).is_empty());
+        assert!(const_interval!(4.0).rootn(i32::MIN).contains(4.0_f64.powf(-1.0 / 2147483648.0)));
+    }
+
+    #[test]
+    fn degrees_radians() {
+        use std::f64::consts::PI;
+
+        assert!(const_interval!(180.0).to_radians().contains(PI));
+        assert!(I::PI.subset(const_interval!(180.0).to_radians()));
+        assert!(I::FRAC_PI_2.subset(const_interval!(90.0).to_radians()));
+        assert!(I::PI.to_degrees().contains(180.0));
+        assert_eq!(const_interval!(0.0).to_radians(), const_interval!(0.0));
+        assert_eq!(const_interval!(0.0).to_degrees(), const_interval!(0.0));
+
+        // The results are enclosures, and narrow ones.
+        let x = const_interval!(-30.0, 45.0).to_radians();
+        assert!(x.contains(-PI / 6.0) && x.contains(PI / 4.0));
+        // 75° ≈ 1.308997 rad
+        assert!(x.sup - x.inf < 1.309);
+        for deg in [1.0, 30.0, 57.0, 360.0, -720.0, 1e300] {
+            let x = interval!(deg).unwrap();
+            assert!(x.subset(x.to_radians().to_degrees()));
+        }
+
+        // The constants are the tightest enclosures.
+        assert_eq!(I::FRAC_PI_180.inf.next_up(), I::FRAC_PI_180.sup);
+        assert_eq!(I::FRAC_180_PI.inf.next_up(), I::FRAC_180_PI.sup);
+        assert!(I::FRAC_PI_180.contains(PI / 180.0));
+        assert!(I::FRAC_180_PI.contains(180.0 / PI));
+
+        assert_eq!(I::ENTIRE.to_radians(), I::ENTIRE);
+        assert!(I::EMPTY.to_radians().is_empty());
+        assert!(I::EMPTY.to_degrees().is_empty());
+    }
+
+    #[test]
+    fn db() {
+        assert!(const_interval!(0.0).from_db().contains(1.0));
+        assert!(const_interval!(1.0).to_db().contains(0.0));
+        // ±6 dB ≈ ×0.501, ×1.995
+        let x = const_interval!(-6.0, 6.0).from_db();
+        assert!(x.inf > 0.501 && x.inf < 0.502 && x.sup > 1.995 && x.sup < 1.996);
+        let x = const_interval!(0.5, 2.0).to_db();
+        assert!(x.inf > -6.03 && x.inf < -6.02 && x.sup > 6.02 && x.sup < 6.03);
+        assert!(const_interval!(100.0).to_db().contains(40.0));
+        assert!(const_interval!(-40.0).from_db().contains(0.01));
+
+        // The factor is 20, not 10.
+        assert_eq!(const_interval!(10.0).to_db(), const_interval!(20.0));
+        assert_eq!(const_interval!(20.0).from_db(), const_interval!(10.0));
+
+        assert_eq!(const_interval!(0.0, 1.0).to_db(), const_interval!(f64::NEG_INFINITY, 0.0));
+        assert_eq!(I::ENTIRE.to_db(), I::ENTIRE);
+        assert!(const_interval!(-2.0, 0.0).to_db().is_empty());
+        assert!(I::EMPTY.to_db().is_empty());
+        assert!(I::EMPTY.from_db().is_empty());
+    }
+
+    #[test]
+    fn entire() {
+        const INF: f64 = f64::INFINITY;
+        let x = I::ENTIRE;
+        assert_eq!(x.asinh(), I::ENTIRE);
+        assert_eq!(x.atan(), interval!(-I::FRAC_PI_2.sup, I::FRAC_PI_2.sup).unwrap());
+        assert_eq!(x.cbrt(), I::ENTIRE);
+        assert_eq!(x.cos(), const_interval!(-1.0, 1.0));
+        assert_eq!(x.cos_with_pi(I::PI), const_interval!(-1.0, 1.0));
+        assert_eq!(x.cosh(), const_interval!(1.0, INF));
+        assert_eq!(x.exp(), const_interval!(0.0, INF));
+        assert_eq!(x.exp10(), const_interval!(0.0, INF));
+        assert_eq!(x.exp2(), const_interval!(0.0, INF));
+        assert_eq!(x.exp_m1(), const_interval!(-1.0, INF));
+        assert_eq!(x.ln_1p(), I::ENTIRE);
+        assert_eq!(x.sin(), const_interval!(-1.0, 1.0));
+        assert_eq!(x.sin_with_pi(I::PI), const_interval!(-1.0, 1.0));
+        assert_eq!(x.sinh(), I::ENTIRE);
+        assert_eq!(x.tan(), I::ENTIRE);
+        assert_eq!(x.tanh(), const_interval!(-1.0, 1.0));
+
+        // The range of atan is (-π/2, π/2), which f64::atan(±∞) does not enclose.
+        assert!(x.atan().contains(std::f64::consts::FRAC_PI_2.next_up()));
+    }
+
+    #[test]
+    fn cbrt() {
+        assert_eq!(const_interval!(-8.0, 27.0).cbrt(), const_interval!(-2.0, 3.0));
+        assert_eq!(const_interval!(-1.0, 0.0).cbrt(), const_interval!(-1.0, 0.0));
+        assert_eq!(const_interval!(f64::NEG_INFINITY, 8.0).cbrt(), const_interval!(f64::NEG_INFINITY, 2.0));
+        assert!(I::EMPTY.cbrt().is_empty());
+    }
+
+    #[test]
+    fn trig_pi() {
+        const INF: f64 = f64::INFINITY;
+        assert_eq!(const_interval!(0.0, 0.5).sin_pi(), const_interval!(0.0, 1.0));
+        assert_eq!(const_interval!(0.5, 1.5).sin_pi(), const_interval!(-1.0, 1.0));
+        assert_eq!(const_interval!(1.0, 1.0).sin_pi(), const_interval!(0.0, 0.0));
+        assert_eq!(const_interval!(-2.0, -1.5).sin_pi(), const_interval!(0.0, 1.0));
+        assert_eq!(const_interval!(0.25, 2.5).sin_pi(), const_interval!(-1.0, 1.0));
+        assert_eq!(const_interval!(-INF, 0.0).sin_pi(), const_interval!(-1.0, 1.0));
+
+        assert_eq!(const_interval!(0.0, 0.5).cos_pi(), const_interval!(0.0, 1.0));
+        assert_eq!(const_interval!(-0.5, 0.5).cos_pi(), const_interval!(0.0, 1.0));
+        assert_eq!(const_interval!(0.5, 1.5).cos_pi(), const_interval!(-1.0, 0.0));
+        assert_eq!(const_interval!(1e300, 1e300).cos_pi(), const_interval!(1.0, 1.0));
+
+        assert_eq!(const_interval!(0.0, 0.25).tan_pi(), const_interval!(0.0, 1.0));
+        assert_eq!(const_interval!(0.5, 0.75).tan_pi(), const_interval!(-INF, -1.0));
+        assert_eq!(const_interval!(0.75, 1.25).tan_pi(), const_interval!(-1.0, 1.0));
+        assert_eq!(const_interval!(0.0, 0.5).tan_pi(), I::ENTIRE);
+        assert!(const_interval!(1.5, 1.5).tan_pi().is_empty());
+        assert_eq!(const_interval!(-0.5, -0.25).tan_pi(), const_interval!(-INF, -1.0));
+        assert_eq!(const_interval!(-1.25, -0.75).tan_pi(), const_interval!(-1.0, 1.0));
+
+        // Small negative numbers and intervals straddling zero, whose reduction by
+        // `rem_euclid` would round to the other end of the period.
+        let x = const_interval!(-1e-17, 1e-17);
+        for y in [x.sin_pi(), x.tan_pi()] {
+            assert!(y.inf < 0.0 && 0.0 < y.sup);
+            assert_eq!(y, -y);
+        }
+        let y = const_interval!(-1e-300, 0.0).sin_pi();
+        assert!(y.inf < 0.0 && y.sup == 0.0);
+        let y = const_interval!(-1e-300, 0.0).tan_pi();
+        assert!(y.inf < 0.0 && y.sup == 0.0);
+        assert_eq!(const_interval!(-0.5, -0.0).sin_pi(), const_interval!(-1.0, 0.0));
+
+        for x in [I::EMPTY, I::ENTIRE] {
+            assert_eq!(x.sin_pi(), x.sin());
+            assert_eq!(x.cos_pi(), x.cos());
+            assert_eq!(x.tan_pi(), x.tan());
+        }
+
+        // The multiplication by π loses the precision needed for the reduction.
+        let x = const_interval!(1e15, 1e15 + 0.5);
+        assert_eq!(x.sin_pi(), const_interval!(0.0, 1.0));
+        assert!((x * I::PI).sin().inf < -0.1);
+    }
+
+    #[test]
+    fn gamma() {
+        const INF: f64 = f64::INFINITY;
+
+        // Monotonic on either side of the minimum.
+        let x = const_interval!(2.0, 4.0).gamma();
+        assert_eq!(x, const_interval!(1.0, 6.0));
+        let x = const_interval!(0.5, 1.0).gamma();
+        assert_eq!(x.sup, libm::tgamma(0.5));
+        assert_eq!(x.inf, 1.0);
+
+        // The interval straddles the minimum.
+        let x = const_interval!(1.0, 2.0).gamma();
+        assert_eq!(x, interval!(0.8856031944108886, 1.0).unwrap());
+        assert!(x.contains(libm::tgamma(1.4616321449683622)));
+        assert!(const_interval!(1.4, 1.5).gamma().inf < libm::tgamma(1.4616321449683622));
+
+        // Poles.
+        assert_eq!(const_interval!(-0.5, 0.5).gamma(), I::ENTIRE);
+        assert_eq!(const_interval!(-2.5, -1.5).gamma(), I::ENTIRE);
+        assert_eq!(const_interval!(0.0, 1.0).gamma(), const_interval!(1.0, INF));
+        assert_eq!(const_interval!(-0.0, 1.0).gamma(), const_interval!(1.0, INF));
+        assert!(const_interval!(0.0).gamma().is_empty());
+        assert!(const_interval!(-3.0).gamma().is_empty());
+        assert_eq!(I::ENTIRE.gamma(), I::ENTIRE);
+        assert!(I::EMPTY.gamma().is_empty());
+
+        // Between poles.
+        let x = const_interval!(-1.0, -0.25).gamma();
+        assert_eq!(x.inf, -INF);
+        assert!(x.contains(libm::tgamma(-0.5040830082644554)) && x.sup < 0.0);
+        let x = const_interval!(-2.0, -1.0).gamma();
+        assert_eq!(x.sup, INF);
+        assert!(x.inf > 0.0 && x.contains(libm::tgamma(-1.5)));
+        let x = const_interval!(-1.5).gamma();
+        assert_eq!(x, interval!(libm::tgamma(-1.5), libm::tgamma(-1.5)).unwrap());
+
+        // Monotonic parts of the cells, on either side of the extremum at -0.5040830082644554.
+        let x = const_interval!(-0.9, -0.8).gamma();
+        assert_eq!(x, interval!(libm::tgamma(-0.9), libm::tgamma(-0.8)).unwrap());
+        assert!(x.sup < -5.7);
+        let x = const_interval!(-0.3, -0.2).gamma();
+        assert_eq!(x, interval!(libm::tgamma(-0.2), libm::tgamma(-0.3)).unwrap());
+        let x = const_interval!(-1.9, -1.8).gamma();
+        assert_eq!(x, interval!(libm::tgamma(-1.8), libm::tgamma(-1.9)).unwrap());
+        // ψ(1) = -γ, and ψ vanishes at the extrema.
+        assert!((super::digamma(1.0) + 0.5772156649015329).abs() < 1e-10);
+        assert!(super::digamma(-0.5040830082644554).abs() < 1e-8);
+        assert!(super::digamma(-1.5734984731623905).abs() < 1e-8);
+
+        // The extremum is enclosed.
+        let x = const_interval!(-0.6, -0.4).gamma();
+        assert!(x.contains(libm::tgamma(-0.5040830082644554)) && x.inf == libm::tgamma(-0.4));
+
+        // Overflow.
+        let x = const_interval!(200.0, 300.0).gamma();
+        assert_eq!(x, interval!(f64::MAX, INF).unwrap());
+        let x = const_interval!(1e307, 1e308).ln_gamma();
+        assert_eq!(x, interval!(f64::MAX, INF).unwrap());
+        assert_eq!(const_interval!(170.0, 200.0).gamma().sup, INF);
+
+        let x = const_interval!(1.0, 3.0).ln_gamma();
+        assert_eq!(x, interval!(-0.12148629053584963, 2.0f64.ln()).unwrap());
+        assert_eq!(const_interval!(-1.0, 1.0).ln_gamma(), const_interval!(0.0, INF));
+        assert!(const_interval!(-1.0, 0.0).ln_gamma().is_empty());
+    }
+
+    #[test]
+    fn exp_m1_ln_1p() {
+        assert_eq!(const_interval!(0.0, 0.0).exp_m1(), const_interval!(0.0, 0.0));
+        assert_eq!(const_interval!(0.0, 0.0).ln_1p(), const_interval!(0.0, 0.0));
+        assert_eq!(const_interval!(-2.0, 1.0).ln_1p(), interval!(f64::NEG_INFINITY, 2.0f64.ln()).unwrap());
+        assert!(const_interval!(-3.0, -1.0).ln_1p().is_empty());
+        assert!(I::EMPTY.exp_m1().is_empty());
+        assert!(I::EMPTY.ln_1p().is_empty());
+    }
+
+    #[test]
+    fn hypot() {
+        const INF: f64 = f64::INFINITY;
+        assert_eq!(const_interval!(-3.0, 3.0).hypot(const_interval!(0.0, 4.0)), const_interval!(0.0, 5.0));
+        assert_eq!(const_interval!(3.0).hypot(const_interval!(-4.0)), const_interval!(5.0));
+        assert_eq!(const_interval!(-6.0, -3.0).hypot(const_interval!(4.0, 8.0)), const_interval!(5.0, 10.0));
+        assert_eq!(const_interval!(1.0, INF).hypot(const_interval!(0.0)), const_interval!(1.0, INF));
+        assert_eq!(I::ENTIRE.hypot(I::ENTIRE), const_interval!(0.0, INF));
+        assert!(I::EMPTY.hypot(const_interval!(1.0)).is_empty());
+        assert!(const_interval!(1.0).hypot(I::EMPTY).is_empty());
+    }
+
+    #[test]
+    fn sqr() {
+        let xs = [
+            const_interval!(-3.0, 2.0),
+            const_interval!(-2.0, 3.0),
+            const_interval!(2.0, 3.0),
+            const_interval!(-3.0, -2.0),
+            const_interval!(0.0, 3.0),
+            const_interval!(-3.0, 0.0),
+            const_interval!(0.0),
+            const_interval!(-0.0, 0.0),
+            const_interval!(0.1, 0.3),
+            const_interval!(1e100, 1e300),
+            const_interval!(f64::NEG_INFINITY, -1.0),
+            I::ENTIRE,
+            I::EMPTY,
+        ];
+        for x in xs {
+            assert!(x.sqr().subset(x * x), "{}", x);
+            if !x.contains(0.0) {
+                // Without the dependency problem, the result is the same as the product.
+                assert_eq!(x.sqr(), x * x, "{}", x);
+            }
+        }
+        // 0.1 is not representable, and the exact square of the f64 number is strictly enclosed.
+        assert_eq!(const_interval!(0.1).sqr(), interval!(0.01, 0.010000000000000002).unwrap());
+        assert_eq!(const_interval!(-3.0, 2.0).sqr(), const_interval!(0.0, 9.0));
+        assert_eq!(const_interval!(1e100, 1e300).sqr().sup, f64::INFINITY);
+    }
+
+    #[test]
+    fn sqrt() {
+        // No widening for exact square roots.
+        assert_eq!(const_interval!(4.0).sqrt(), const_interval!(2.0));
+        assert_eq!(const_interval!(0.25, 1e100).sqrt(), const_interval!(0.5, 1e50));
+        assert_eq!(const_interval!(0.0).sqrt(), const_interval!(0.0));
+
+        // √2 is not representable, and f64::sqrt(2.0) is above it.
+        let x = const_interval!(2.0).sqrt();
+        assert_eq!(x, interval!(std::f64::consts::SQRT_2.next_down(), std::f64::consts::SQRT_2).unwrap());
+
+        assert_eq!(I::ENTIRE.sqrt(), const_interval!(0.0, f64::INFINITY));
+        assert!(const_interval!(-2.0, -1.0).sqrt().is_empty());
+    }
+
+    #[test]
+    fn sqrt_square_encloses() {
+        // x.sqrt().sqr() ⊇ x ∩ [0, +∞]
+        let mut us = sample_f64s(0x2545f4914f6cdd1d, 20000);
+
+        for _ in 0..10000 {
+            let a = us.next().unwrap() * 200.0 - 100.0;
+            let b = a + us.next().unwrap() * 100.0;
+            let x = interval!(a, b).unwrap();
+            let y = x.sqrt().sqr();
+            let x_nonneg = x.intersection(const_interval!(0.0, f64::INFINITY));
+            assert!(x_nonneg.subset(y), "{} is not a subset of {}", x_nonneg, y);
+        }
+
+        for x in [const_interval!(2.0), const_interval!(3.0, 5.0), const_interval!(1e-300, 1e300), I::ENTIRE] {
+            let x_nonneg = x.intersection(const_interval!(0.0, f64::INFINITY));
+            assert!(x_nonneg.subset(x.sqrt().sqr()));
+        }
+    }
+
+    #[test]
+    fn sqrt_status() {
+        use DomainStatus::*;
+
+        assert_eq!(const_interval!(4.0, 9.0).sqrt_status(), (const_interval!(2.0, 3.0), Interior));
+        assert_eq!(const_interval!(0.0, 4.0).sqrt_status(), (const_interval!(0.0, 2.0), Interior));
+        assert_eq!(const_interval!(0.0).sqrt_status(), (const_interval!(0.0), Interior));
+        assert_eq!(const_interval!(-1.0, 4.0).sqrt_status(), (const_interval!(0.0, 2.0), ClippedLow));
+        assert_eq!(const_interval!(-1.0, 0.0).sqrt_status(), (const_interval!(0.0), ClippedLow));
+        assert_eq!(I::ENTIRE.sqrt_status(), (const_interval!(0.0, f64::INFINITY), ClippedLow));
+        assert_eq!(const_interval!(-2.0, -1.0).sqrt_status(), (I::EMPTY, Empty));
+        assert_eq!(I::EMPTY.sqrt_status(), (I::EMPTY, Empty));
+
+        for x in [const_interval!(1.0, 2.0), const_interval!(-1.0, 2.0), const_interval!(-2.0, -1.0)] {
+            assert_eq!(x.sqrt_status().0, x.sqrt());
+        }
+    }
+
+    #[test]
+    fn sin_cos_with_pi() {
+        let xs = [
+            I::EMPTY,
+            I::ENTIRE,
+            const_interval!(0.0),
+            const_interval!(-1.0, 2.0),
+            const_interval!(1e10, 1e10 + 1.0),
+        ];
+        for x in xs {
+            assert_eq!(x.sin_with_pi(I::PI), x.sin());
+            assert_eq!(x.cos_with_pi(I::PI), x.cos());
+        }
+
+        // A wider enclosure of π makes the period counting ambiguous for a large argument.
+        let pi = I::PI + const_interval!(-1e-4, 1e-4);
+        let x = const_interval!(1572.377, 1572.387); // 1001 π/2 + [0.01, 0.02]
+        assert!(x.sin().subset(x.sin_with_pi(pi)));
+        assert!(x.sin().sup - x.sin().inf < x.sin_with_pi(pi).sup - x.sin_with_pi(pi).inf);
+        let x = const_interval!(1570.806, 1570.816); // 1000 π/2 + [0.01, 0.02]
+        assert!(x.cos().subset(x.cos_with_pi(pi)));
+        assert!(x.cos().sup - x.cos().inf < x.cos_with_pi(pi).sup - x.cos_with_pi(pi).inf);
+    }
+
+    #[test]
+    fn atan2_samples() {
+        const N: usize = 40;
+        let classes = [
+            const_interval!(-3.0, -1.0), // N1
+            const_interval!(-2.0, 0.0),  // N0
+            const_interval!(-1.0, 2.0),  // M
+            const_interval!(0.0, 2.0),   // P0
+            const_interval!(1.0, 3.0),   // P1
+            const_interval!(0.0),        // Z
+        ];
+        let sample = |x: I, i: usize| {
+            // Adding 0.0 turns -0.0 into +0.0, for which atan2 returns +π on the negative x-axis.
+            (x.inf + (x.sup - x.inf) * (i as f64 / N as f64)).clamp(x.inf, x.sup) + 0.0
+        };
+
+        for x in classes {
+            for y in classes {
+                let z = y.atan2(x);
+                assert!(z.subset(interval!(-I::PI.sup, I::PI.sup).unwrap()));
+                for i in 0..=N {
+                    for j in 0..=N {
+                        let (s, t) = (sample(x, i), sample(y, j));
+                        if s == 0.0 && t == 0.0 {
+                            continue;
+                        }
+                        let w = t.atan2(s);
+                        assert!(
+                            z.contains(w),
+                            "atan2({}, {}) = {} is not contained in atan2({}, {}) = {}",
+                            t,
+                            s,
+                            w,
+                            y,
+                            x,
+                            z
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn atan2_axes() {
+        let pos = const_interval!(1.0, 2.0);
+        let neg = const_interval!(-2.0, -1.0);
+        let zero = const_interval!(0.0);
+
+        // Positive and negative x-axes.
+        assert_eq!(zero.atan2(pos), zero);
+        assert_eq!(zero.atan2(neg), I::PI);
+        assert_eq!(zero.atan2(const_interval!(0.0, 2.0)), zero);
+        assert_eq!(zero.atan2(const_interval!(-2.0, 0.0)), I::PI);
+
+        // Positive and negative y-axes.
+        assert_eq!(pos.atan2(zero), I::FRAC_PI_2);
+        assert_eq!(neg.atan2(zero), -I::FRAC_PI_2);
+        assert_eq!(const_interval!(0.0, 2.0).atan2(zero), I::FRAC_PI_2);
+        assert_eq!(const_interval!(-2.0, 0.0).atan2(zero), -I::FRAC_PI_2);
+
+        // The origin.
+        assert!(zero.atan2(zero).is_empty());
+        assert!(I::EMPTY.atan2(pos).is_empty());
+        assert!(pos.atan2(I::EMPTY).is_empty());
+
+        assert_eq!(I::ENTIRE.atan2(I::ENTIRE), interval!(-I::PI.sup, I::PI.sup).unwrap());
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn atan2_itf1788() {
+        // The test cases of atan2 from ITF1788 (libieeep1788), with the literal bounds.
+        // The bounds computed by f64::atan2 are rounded to nearest instead of outward,
+        // so they can be one ulp inside the literal ones.
+        let cases: [(I, I, f64, f64); 19] = [
+            (I::ENTIRE, I::ENTIRE, -3.1415926535897936, 3.1415926535897936),
+            (const_interval!(-2.0, 2.0), const_interval!(-3.0, -1.0), -3.1415926535897936, 3.1415926535897936),
+            (const_interval!(-2.0, 0.0), const_interval!(-3.0, -1.0), -3.1415926535897936, 3.1415926535897936),
+            (const_interval!(-2.0, 0.0), const_interval!(-3.0, 0.0), -3.1415926535897936, 3.1415926535897936),
+            (const_interval!(-2.0, 2.0), const_interval!(-3.0, 0.0), -3.1415926535897936, 3.1415926535897936),
+            (const_interval!(0.0, 2.0), const_interval!(-3.0, -1.0), 2.0344439357957027, 3.1415926535897936),
+            (const_interval!(1.0, 3.0), const_interval!(-3.0, -1.0), 1.8925468811915387, 2.8198420991931514),
+            (const_interval!(1.0, 3.0), const_interval!(-2.0, 0.0), 1.5707963267948966, 2.6779450445889874),
+            (const_interval!(1.0, 3.0), const_interval!(-2.0, 2.0), 0.4636476090008061, 2.6779450445889874),
+            (const_interval!(1.0, 3.0), const_interval!(0.0, 2.0), 0.4636476090008061, 1.5707963267948968),
+            (const_interval!(1.0, 3.0), const_interval!(1.0, 3.0), 0.3217505543966422, 1.2490457723982544),
+            (const_interval!(0.0, 2.0), const_interval!(1.0, 3.0), 0.0, 1.1071487177940906),
+            (const_interval!(-2.0, 2.0), const_interval!(1.0, 3.0), -1.1071487177940906, 1.1071487177940906),
+            (const_interval!(-2.0, 0.0), const_interval!(1.0, 3.0), -1.1071487177940906, 0.0),
+            (const_interval!(-3.0, -1.0), const_interval!(1.0, 3.0), -1.2490457723982544, -0.3217505543966422),
+            (const_interval!(-3.0, -1.0), const_interval!(0.0, 2.0), -1.5707963267948968, -0.4636476090008061),
+            (const_interval!(-3.0, -1.0), const_interval!(-2.0, 2.0), -2.6779450445889874, -0.4636476090008061),
+            (const_interval!(-3.0, -1.0), const_interval!(-2.0, 0.0), -2.6779450445889874, -1.5707963267948966),
+            (const_interval!(-3.0, -1.0), const_interval!(-3.0, -1.0), -2.8198420991931514, -1.8925468811915387),
+        ];
+        for (y, x, inf, sup) in cases {
+            let z = y.atan2(x);
+            assert!(inf <= z.inf && z.inf <= inf.next_up(), "atan2({}, {}) = {}", y, x, z);
+            assert!(sup.next_down() <= z.sup && z.sup <= sup, "atan2({}, {}) = {}", y, x, z);
+        }
+
+        // Exact results on the axes.
+        let cases = [
+            (const_interval!(0.0), const_interval!(-3.0, -1.0), I::PI),
+            (const_interval!(-0.0, 0.0), const_interval!(-3.0, -1.0), I::PI),
+            (const_interval!(0.0), const_interval!(1.0, 3.0), const_interval!(0.0)),
+            (const_interval!(1.0, 3.0), const_interval!(0.0), I::FRAC_PI_2),
+            (const_interval!(-3.0, -1.0), const_interval!(0.0), -I::FRAC_PI_2),
+            (const_interval!(0.0), const_interval!(0.0), I::EMPTY),
+        ];
+        for (y, x, z) in cases {
+            assert_eq!(y.atan2(x), z, "atan2({}, {})", y, x);
+        }
+
+        // The bounds on the y-axis enclose ±π/2.
+        let z = const_interval!(1.0, 3.0).atan2(const_interval!(0.0, 2.0));
+        assert!(I::FRAC_PI_2.subset(z));
+        let z = const_interval!(-3.0, -1.0).atan2(const_interval!(0.0, 2.0));
+        assert!((-I::FRAC_PI_2).subset(z));
+    }
+
+    #[test]
+    fn to_polar() {
+        let (r, theta) = I::to_polar(const_interval!(1.0), const_interval!(1.0));
+        assert!(r.contains(std::f64::consts::SQRT_2));
+        assert!(theta.contains(std::f64::consts::FRAC_PI_4));
+
+        let (r, theta) = I::to_polar(const_interval!(-1.0, 1.0), const_interval!(3.0, 4.0));
+        assert_eq!(r, interval!(3.0, 17.0_f64.sqrt()).unwrap());
+        assert!(theta.contains(-0.25_f64.atan()) && theta.contains(0.25_f64.atan()));
+
+        let (r, theta) = I::to_polar(const_interval!(0.0), const_interval!(-2.0));
+        assert_eq!(r, const_interval!(2.0));
+        assert_eq!(theta, I::PI);
+
+        let (r, theta) = I::to_polar(const_interval!(0.0), const_interval!(0.0));
+        assert_eq!(r, const_interval!(0.0));
+        assert!(theta.is_empty());
+
+        let (r, theta) = I::to_polar(I::EMPTY, const_interval!(1.0));
+        assert!(r.is_empty() && theta.is_empty());
+
+        // The radius does not overflow where the squares do.
+        let (r, theta) = I::to_polar(const_interval!(1e200), const_interval!(1e200));
+        assert!(r.is_common_interval() && r.contains(1e200 * std::f64::consts::SQRT_2));
+        assert!(theta.contains(std::f64::consts::FRAC_PI_4));
+    }
+
     #[test]
     fn tan() {
         // a, b ∈ (-π/2, π/2)
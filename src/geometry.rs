@@ -0,0 +1,351 @@
+use crate::{const_interval, interval::*};
+
+/// A two-dimensional box, the Cartesian product of two intervals `x` × `y`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Box2 {
+    pub x: Interval,
+    pub y: Interval,
+}
+
+impl Box2 {
+    /// Creates the box `x` × `y`.
+    pub fn new(x: Interval, y: Interval) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns `true` if either side of `self` is empty.
+    pub fn is_empty(self) -> bool {
+        self.x.is_empty() || self.y.is_empty()
+    }
+
+    /// Returns an enclosure of the image of `self` under the rotation about the origin
+    /// by `angle` (counterclockwise, in radians):
+    ///
+    /// $$
+    /// (x, y) ↦ (x \cos θ - y \sin θ, x \sin θ + y \cos θ).
+    /// $$
+    ///
+    /// The result is the interval evaluation of the formula above. It is nearly tight when
+    /// `angle` is narrow, but for a wide `angle` the image is overestimated,
+    /// since the dependency between $\sin θ$ and $\cos θ$, and between the two occurrences
+    /// of each of $x$ and $y$, is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let b = Box2::new(const_interval!(1.0, 2.0), const_interval!(3.0, 4.0));
+    /// assert_eq!(b.rotate(const_interval!(0.0)), b);
+    /// ```
+    #[must_use]
+    pub fn rotate(self, angle: Interval) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self {
+            x: self.x * c - self.y * s,
+            y: self.x * s + self.y * c,
+        }
+    }
+
+    /// Returns an enclosure of the image of `self` under the inversion through the unit circle:
+    ///
+    /// $$
+    /// (x, y) ↦ \frac{(x, y)}{x^2 + y^2}.
+    /// $$
+    ///
+    /// The origin, where the inversion is undefined, is excluded; if `self` contains
+    /// the origin, the image is unbounded, and if `self` consists only of the origin,
+    /// the result is empty.
+    ///
+    /// As with [`Box2::rotate`], the result is the interval evaluation of the formula above,
+    /// and is overestimated for wide boxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let b = Box2::new(const_interval!(2.0), const_interval!(0.0));
+    /// assert_eq!(b.invert_unit_circle(), Box2::new(const_interval!(0.5), const_interval!(0.0)));
+    /// ```
+    #[must_use]
+    pub fn invert_unit_circle(self) -> Self {
+        let r2 = self.x.sqr() + self.y.sqr();
+        Self {
+            x: self.x / r2,
+            y: self.y / r2,
+        }
+    }
+}
+
+impl Interval {
+    /// Returns an enclosure of the range of the cubic Bézier curve with the control points `p`
+    /// over the parameter interval `t`:
+    ///
+    /// $$
+    /// B(t) = (1 - t)^3 p_0 + 3 (1 - t)^2 t p_1 + 3 (1 - t) t^2 p_2 + t^3 p_3.
+    /// $$
+    ///
+    /// `t` is restricted to $\[0, 1\]$. The curve is evaluated by de Casteljau's algorithm,
+    /// which is usually tighter than the evaluation of the expanded polynomial,
+    /// and the result is intersected with the convex hull of the control points,
+    /// which contains the whole curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let p = [
+    ///     const_interval!(0.0),
+    ///     const_interval!(1.0),
+    ///     const_interval!(2.0),
+    ///     const_interval!(3.0),
+    /// ];
+    /// assert_eq!(Interval::bezier_bound(&p, const_interval!(0.5)), const_interval!(1.5));
+    /// assert_eq!(Interval::bezier_bound(&p, const_interval!(0.0, 1.0)), const_interval!(0.0, 3.0));
+    /// ```
+    #[must_use]
+    pub fn bezier_bound(p: &[Interval; 4], t: Interval) -> Self {
+        const DOM: Interval = const_interval!(0.0, 1.0);
+        let t = t.intersection(DOM);
+
+        if t.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let s = const_interval!(1.0) - t;
+        let lerp = |a: Interval, b: Interval| s * a + t * b;
+
+        let q = [lerp(p[0], p[1]), lerp(p[1], p[2]), lerp(p[2], p[3])];
+        let r = [lerp(q[0], q[1]), lerp(q[1], q[2])];
+        let b = lerp(r[0], r[1]);
+
+        let hull = p[1..].iter().fold(p[0], |h, &x| h.convex_hull(x));
+        b.intersection(hull)
+    }
+
+    /// Returns an enclosure of the range of the smoothstep function over `self`:
+    ///
+    /// $$
+    /// \operatorname{smoothstep}(x) = 3t^2 - 2t^3, \quad
+    /// t = \min \set{\max \set{(x - \mathrm{edge_0}) / (\mathrm{edge_1} - \mathrm{edge_0}), 0}, 1}.
+    /// $$
+    ///
+    /// Since the function is monotonically increasing, the result is obtained by evaluating it
+    /// at the bounds of `self`.
+    ///
+    /// If `edge0 >= edge1`, the function degenerates to the step function that is 0 for
+    /// $x < \mathrm{edge_0}$ and 1 for $x ≥ \mathrm{edge_0}$.
+    /// The result is $∅$ if `self` is empty or either edge is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-1.0, 0.5).smoothstep(0.0, 1.0), const_interval!(0.0, 0.5));
+    /// assert_eq!(const_interval!(2.0, 3.0).smoothstep(0.0, 1.0), const_interval!(1.0));
+    /// assert_eq!(const_interval!(-1.0, 1.0).smoothstep(0.0, 0.0), const_interval!(0.0, 1.0));
+    /// ```
+    #[must_use]
+    pub fn smoothstep(self, edge0: f64, edge1: f64) -> Self {
+        if self.is_empty() || edge0.is_nan() || edge1.is_nan() {
+            return Self::EMPTY;
+        }
+
+        if edge0 >= edge1 {
+            let inf = if self.inf >= edge0 { 1.0 } else { 0.0 };
+            let sup = if self.sup >= edge0 { 1.0 } else { 0.0 };
+            return Self::with_infsup_raw(inf, sup);
+        }
+
+        let e0 = Self::with_infsup_raw(edge0, edge0);
+        let e1 = Self::with_infsup_raw(edge1, edge1);
+        let t = (self - e0) / (e1 - e0);
+        // 3t^2 - 2t^3 = t^2 (3 - 2t)
+        let f = |t: f64| {
+            let t = Self::with_infsup_raw(t.clamp(0.0, 1.0), t.clamp(0.0, 1.0));
+            t.sqr() * (const_interval!(3.0) - const_interval!(2.0) * t)
+        };
+
+        Self::with_infsup_raw(f(t.inf).inf, f(t.sup).sup)
+    }
+
+    /// Splits `domain` into `screen_width_px` subintervals of equal width,
+    /// one for each pixel column of a graph, and returns each of them paired with
+    /// `f` evaluated over it.
+    ///
+    /// If `f` returns an enclosure of the range of a function, the pairs are the vertical bars
+    /// that are guaranteed to contain the graph of the function.
+    /// The subintervals are the ones given by [`Interval::mince`], so adjacent ones share their endpoints.
+    /// `screen_width_px` is treated as 1 if it is zero.
+    ///
+    /// The result is empty if `domain` is empty. If `domain` is unbounded, it cannot be split
+    /// into subintervals of equal width, and the result is the single pair `(domain, f(domain))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let cols = Interval::sample_function(const_interval!(0.0, 1.0), 4, |x| x.powi(2));
+    /// assert_eq!(cols.len(), 4);
+    /// assert_eq!(cols[0], (const_interval!(0.0, 0.25), const_interval!(0.0, 0.0625)));
+    /// ```
+    pub fn sample_function<F: Fn(Interval) -> Interval>(
+        domain: Interval,
+        screen_width_px: usize,
+        f: F,
+    ) -> Vec<(Interval, Interval)> {
+        if domain.is_empty() {
+            return vec![];
+        }
+
+        domain
+            .mince(screen_width_px.max(1))
+            .into_iter()
+            .map(|x| (x, f(x)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn rotate() {
+        let b = Box2::new(const_interval!(1.0, 2.0), const_interval!(3.0, 4.0));
+
+        assert_eq!(b.rotate(const_interval!(0.0)), b);
+
+        // (x, y) ↦ (-y, x)
+        let r = b.rotate(I::FRAC_PI_2);
+        assert!(const_interval!(-4.0, -3.0).subset(r.x));
+        assert!(const_interval!(1.0, 2.0).subset(r.y));
+        assert!(r.x.sup - r.x.inf < 1.0 + 1e-14);
+        assert!(r.y.sup - r.y.inf < 1.0 + 1e-14);
+
+        assert!(b.rotate(I::EMPTY).is_empty());
+        assert!(Box2::new(I::EMPTY, I::PI).rotate(I::PI).is_empty());
+    }
+
+    #[test]
+    fn invert_unit_circle() {
+        // Away from the origin.
+        let b = Box2::new(const_interval!(1.0, 2.0), const_interval!(1.0, 2.0));
+        let c = b.invert_unit_circle();
+        for (x, y) in [(1.0, 1.0), (1.0, 2.0), (2.0, 1.0), (2.0, 2.0), (1.5, 1.25)] {
+            let r2 = x * x + y * y;
+            assert!(c.x.contains(x / r2) && c.y.contains(y / r2));
+        }
+        assert!(c.x.is_common_interval() && c.y.is_common_interval());
+
+        // Points on the unit circle are fixed.
+        let b = Box2::new(const_interval!(0.0), const_interval!(-1.0));
+        assert_eq!(b.invert_unit_circle(), b);
+
+        // Straddling the origin.
+        let b = Box2::new(const_interval!(-1.0, 1.0), const_interval!(-1.0, 1.0));
+        assert_eq!(b.invert_unit_circle(), Box2::new(I::ENTIRE, I::ENTIRE));
+
+        // Touching the origin at a corner.
+        let b = Box2::new(const_interval!(0.0, 1.0), const_interval!(0.0, 1.0));
+        let c = b.invert_unit_circle();
+        assert_eq!(c.x.inf, 0.0);
+        assert_eq!(c.x.sup, f64::INFINITY);
+        assert_eq!(c.y.inf, 0.0);
+        assert_eq!(c.y.sup, f64::INFINITY);
+
+        assert!(Box2::new(const_interval!(0.0), const_interval!(0.0)).invert_unit_circle().is_empty());
+        assert!(Box2::new(I::EMPTY, const_interval!(1.0)).invert_unit_circle().is_empty());
+    }
+
+    #[test]
+    fn bezier_bound() {
+        let p = [
+            const_interval!(0.0),
+            const_interval!(3.0),
+            const_interval!(-1.0),
+            const_interval!(2.0),
+        ];
+        let expanded = |t: I| {
+            let s = const_interval!(1.0) - t;
+            s.powi(3) * p[0]
+                + const_interval!(3.0) * s.sqr() * t * p[1]
+                + const_interval!(3.0) * s * t.sqr() * p[2]
+                + t.powi(3) * p[3]
+        };
+
+        for (a, b) in [(0.0, 1.0), (0.0, 0.25), (0.25, 0.5), (0.4, 0.6), (0.9, 1.0)] {
+            let t = interval!(a, b).unwrap();
+            let y = I::bezier_bound(&p, t);
+            let z = expanded(t);
+            assert!(y.sup - y.inf <= z.sup - z.inf);
+
+            for i in 0..=100 {
+                let t = (a + (b - a) * i as f64 / 100.0).min(b);
+                let s = 1.0 - t;
+                let v = s * s * s * p[0].inf
+                    + 3.0 * s * s * t * p[1].inf
+                    + 3.0 * s * t * t * p[2].inf
+                    + t * t * t * p[3].inf;
+                assert!(y.contains(v));
+            }
+        }
+
+        assert!(I::bezier_bound(&p, I::EMPTY).is_empty());
+        assert!(I::bezier_bound(&p, const_interval!(2.0, 3.0)).is_empty());
+        assert_eq!(
+            I::bezier_bound(&p, I::ENTIRE),
+            I::bezier_bound(&p, const_interval!(0.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn smoothstep() {
+        let x = const_interval!(-1.0, 2.0);
+        assert_eq!(x.smoothstep(0.0, 1.0), const_interval!(0.0, 1.0));
+        assert_eq!(x.smoothstep(-4.0, -2.0), const_interval!(1.0));
+        assert_eq!(x.smoothstep(4.0, 6.0), const_interval!(0.0));
+        assert_eq!(const_interval!(0.5).smoothstep(0.0, 1.0), const_interval!(0.5));
+        assert_eq!(const_interval!(2.0, 3.0).smoothstep(0.0, 4.0), const_interval!(0.5, 0.84375));
+
+        for (a, b) in [(-0.5, 0.2), (0.1, 0.3), (0.25, 0.75), (0.6, 1.7)] {
+            let y = interval!(a, b).unwrap().smoothstep(0.0, 1.0);
+            for i in 0..=100 {
+                let t = (a + (b - a) * i as f64 / 100.0).min(b).clamp(0.0, 1.0);
+                assert!(y.contains(t * t * (3.0 - 2.0 * t)));
+            }
+        }
+
+        // Degenerate edges.
+        assert_eq!(x.smoothstep(1.0, 1.0), const_interval!(0.0, 1.0));
+        assert_eq!(x.smoothstep(1.0, 0.0), const_interval!(0.0, 1.0));
+        assert_eq!(x.smoothstep(-1.0, -1.0), const_interval!(1.0));
+        assert_eq!(x.smoothstep(3.0, 3.0), const_interval!(0.0));
+
+        assert!(I::EMPTY.smoothstep(0.0, 1.0).is_empty());
+        assert!(x.smoothstep(f64::NAN, 1.0).is_empty());
+        assert_eq!(I::ENTIRE.smoothstep(0.0, 1.0), const_interval!(0.0, 1.0));
+    }
+
+    #[test]
+    fn sample_function() {
+        let dom = interval!(0.0, 2.0 * I::PI.sup).unwrap();
+        let cols = I::sample_function(dom, 640, |x| x.sin());
+        assert_eq!(cols.len(), 640);
+        assert_eq!(cols.first().unwrap().0.inf, dom.inf);
+        assert_eq!(cols.last().unwrap().0.sup, dom.sup);
+        for w in cols.windows(2) {
+            assert_eq!(w[0].0.sup, w[1].0.inf);
+        }
+        for &(x, y) in &cols {
+            assert!(y.subset(const_interval!(-1.0, 1.0)));
+            assert!(y.contains(x.inf.sin()) && y.contains(x.sup.sin()));
+        }
+        assert!(cols.iter().any(|&(_, y)| y.contains(1.0)));
+        assert!(cols.iter().any(|&(_, y)| y.contains(-1.0)));
+
+        assert_eq!(I::sample_function(dom, 0, |x| x).len(), 1);
+        assert!(I::sample_function(I::EMPTY, 10, |x| x).is_empty());
+        assert_eq!(I::sample_function(I::ENTIRE, 10, |x| x), vec![(I::ENTIRE, I::ENTIRE)]);
+    }
+}
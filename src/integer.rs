@@ -1,4 +1,4 @@
-use crate::{interval::*, classify::*, const_interval};
+use crate::{arith::{div_down, div_up}, interval::*, classify::*, const_interval};
 
 impl Interval {
     /// Rounds `self` to the closest integer toward $+∞$.
@@ -12,7 +12,7 @@ impl Interval {
     /// # Examples
     ///
     /// ```
-    /// use inari::*;
+    /// use inari_wasm::*;
     /// assert_eq!(const_interval!(0.2, 1.2).ceil(), const_interval!(1.0, 2.0));
     /// assert_eq!(const_interval!(0.8, 1.8).ceil(), const_interval!(1.0, 2.0));
     /// assert_eq!(const_interval!(-1.2, -0.2).ceil(), const_interval!(-1.0, 0.0));
@@ -40,7 +40,7 @@ impl Interval {
     /// # Examples
     ///
     /// ```
-    /// use inari::*;
+    /// use inari_wasm::*;
     /// assert_eq!(const_interval!(0.2, 1.2).floor(), const_interval!(0.0, 1.0));
     /// assert_eq!(const_interval!(0.8, 1.8).floor(), const_interval!(0.0, 1.0));
     /// assert_eq!(const_interval!(-1.2, -0.2).floor(), const_interval!(-2.0, -1.0));
@@ -67,7 +67,7 @@ impl Interval {
     /// # Examples
     ///
     /// ```
-    /// use inari::*;
+    /// use inari_wasm::*;
     /// assert_eq!(const_interval!(0.2, 1.2).round(), const_interval!(0.0, 1.0));
     /// assert_eq!(const_interval!(0.5, 1.5).round(), const_interval!(1.0, 2.0));
     /// assert_eq!(const_interval!(0.8, 1.8).round(), const_interval!(1.0, 2.0));
@@ -98,7 +98,7 @@ impl Interval {
     /// # Examples
     ///
     /// ```
-    /// use inari::*;
+    /// use inari_wasm::*;
     /// assert_eq!(const_interval!(0.2, 1.2).round_ties_to_even(), const_interval!(0.0, 1.0));
     /// assert_eq!(const_interval!(0.5, 1.5).round_ties_to_even(), const_interval!(0.0, 2.0));
     /// assert_eq!(const_interval!(0.8, 1.8).round_ties_to_even(), const_interval!(1.0, 2.0));
@@ -112,7 +112,10 @@ impl Interval {
     /// See also: [`Interval::round`].
     #[must_use]
     pub fn round_ties_to_even(self) -> Self {
-        self.round()
+        Self {
+            inf: self.inf.round_ties_even(),
+            sup: self.sup.round_ties_even(),
+        }
     }
 
     /// Returns the sign of `self`.
@@ -130,7 +133,7 @@ impl Interval {
     /// # Examples
     ///
     /// ```
-    /// use inari::*;
+    /// use inari_wasm::*;
     /// assert_eq!(const_interval!(-10.0, -0.1).sign(), const_interval!(-1.0, -1.0));
     /// assert_eq!(const_interval!(0.0, 0.0).sign(), const_interval!(0.0, 0.0));
     /// assert_eq!(const_interval!(0.1, 10.0).sign(), const_interval!(1.0, 1.0));
@@ -148,6 +151,43 @@ impl Interval {
         }
     }
 
+    /// Returns the Heaviside step function of `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{step}(x) = \begin{cases}
+    ///   0 & \for x < 0, \\\\
+    ///   1 & \for x > 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// Since the function is discontinuous at zero, the result is $\[0, 1\]$
+    /// if `self` contains zero, including the case $\self = \[0, 0\]$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-10.0, -0.1).step(), const_interval!(0.0, 0.0));
+    /// assert_eq!(const_interval!(0.1, 10.0).step(), const_interval!(1.0, 1.0));
+    /// assert_eq!(const_interval!(0.0, 10.0).step(), const_interval!(0.0, 1.0));
+    /// assert_eq!(Interval::EMPTY.step(), Interval::EMPTY);
+    /// ```
+    ///
+    /// See also: [`Interval::sign`].
+    #[must_use]
+    pub fn step(self) -> Self {
+        match self.classify() {
+            IntervalClass::E => Self::EMPTY,
+            IntervalClass::N1 => const_interval!(0.0, 0.0),
+            IntervalClass::P1 => const_interval!(1.0, 1.0),
+            IntervalClass::M | IntervalClass::N0 | IntervalClass::P0 | IntervalClass::Z => {
+                const_interval!(0.0, 1.0)
+            }
+        }
+    }
+
     /// Rounds `self` to the closest integer toward zero.
     ///
     /// The domain and the range of the point function are:
@@ -159,7 +199,7 @@ impl Interval {
     /// # Examples
     ///
     /// ```
-    /// use inari::*;
+    /// use inari_wasm::*;
     /// assert_eq!(const_interval!(0.2, 1.2).trunc(), const_interval!(0.0, 1.0));
     /// assert_eq!(const_interval!(0.8, 1.8).trunc(), const_interval!(0.0, 1.0));
     /// assert_eq!(const_interval!(-1.2, -0.2).trunc(), const_interval!(-1.0, 0.0));
@@ -176,4 +216,160 @@ impl Interval {
 			sup: self.sup.trunc()
         }
     }
-}
\ No newline at end of file
+
+    /// Returns the inclusive range `(i, j)` of the indices of the cells of a uniform grid
+    /// with cells $\[k s, (k + 1) s)$, $k ∈ \Z$, that `self` overlaps,
+    /// where $s$ is `cell_size`, i.e., $i = ⌊a / s⌋$ and $j = ⌊b / s⌋$ for $\self = \[a, b\]$.
+    ///
+    /// The result is `None` if `self` is empty or unbounded.
+    /// Indices that do not fit in [`i64`] are saturated.
+    ///
+    /// The quotients are rounded outward, so that no overlapped cell is missed.
+    /// As a result, the range can include an extra cell at either end
+    /// if a bound is very close to a cell boundary.
+    ///
+    /// `cell_size` must be positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-1.5, 2.5).cell_range(1.0), Some((-2, 2)));
+    /// assert_eq!(const_interval!(0.25, 0.5).cell_range(1.0), Some((0, 0)));
+    /// assert_eq!(Interval::ENTIRE.cell_range(1.0), None);
+    /// ```
+    pub fn cell_range(self, cell_size: f64) -> Option<(i64, i64)> {
+        debug_assert!(cell_size > 0.0);
+
+        if !self.is_common_interval() {
+            return None;
+        }
+
+        let i = div_down(self.inf, cell_size).floor() as i64;
+        let j = div_up(self.sup, cell_size).floor() as i64;
+        Some((i, j))
+    }
+
+    /// Returns an iterator over the integers in `self` in ascending order,
+    /// i.e., $⌈a⌉, ⌈a⌉ + 1, …, ⌊b⌋$ for $\self = \[a, b\]$.
+    ///
+    /// The iterator is empty if `self` is empty or unbounded.
+    /// Only the integers that fit in [`i64`] are yielded, so the iterator is always finite,
+    /// though it can be too long to be exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(1.5, 4.2).integers().collect::<Vec<_>>(), vec![2, 3, 4]);
+    /// assert_eq!(const_interval!(0.2, 0.8).integers().count(), 0);
+    /// assert_eq!(Interval::ENTIRE.integers().count(), 0);
+    /// ```
+    pub fn integers(self) -> impl Iterator<Item = i64> {
+        if !self.is_common_interval() {
+            #[allow(clippy::reversed_empty_ranges)]
+            return 1..=0;
+        }
+
+        // [-2^63, 2^63 - 1024], the largest interval of `f64` that fits in i64.
+        const I64_RANGE: Interval = const_interval!(-9223372036854775808.0, 9223372036854774784.0);
+        let x = self.intersection(I64_RANGE);
+        if x.is_empty() {
+            #[allow(clippy::reversed_empty_ranges)]
+            return 1..=0;
+        }
+
+        (x.inf.ceil() as i64)..=(x.sup.floor() as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn round_ties_to_even() {
+        for (t, r) in [(-2.5, -2.0), (-1.5, -2.0), (-0.5, -0.0), (0.5, 0.0), (1.5, 2.0), (2.5, 2.0), (3.5, 4.0)] {
+            let x = interval!(t).unwrap();
+            assert_eq!(x.round_ties_to_even(), interval!(r).unwrap(), "{}", x);
+        }
+
+        assert_eq!(const_interval!(0.5, 1.5).round_ties_to_even(), const_interval!(0.0, 2.0));
+        assert_eq!(const_interval!(0.5, 1.5).round(), const_interval!(1.0, 2.0));
+        assert_eq!(const_interval!(-1.5, -0.5).round_ties_to_even(), const_interval!(-2.0, 0.0));
+        assert_eq!(const_interval!(-1.5, -0.5).round(), const_interval!(-2.0, -1.0));
+        assert_eq!(const_interval!(2.5, 3.5).round_ties_to_even(), const_interval!(2.0, 4.0));
+        assert_eq!(const_interval!(0.2, 1.2).round_ties_to_even(), const_interval!(0.0, 1.0));
+        assert_eq!(const_interval!(-1.8, -0.8).round_ties_to_even(), const_interval!(-2.0, -1.0));
+        assert!(I::EMPTY.round_ties_to_even().is_empty());
+        assert_eq!(I::ENTIRE.round_ties_to_even(), I::ENTIRE);
+    }
+
+    #[test]
+    fn step() {
+        assert_eq!(const_interval!(-2.0, -1.0).step(), const_interval!(0.0)); // N1
+        assert_eq!(const_interval!(-1.0, 0.0).step(), const_interval!(0.0, 1.0)); // N0
+        assert_eq!(const_interval!(-1.0, 1.0).step(), const_interval!(0.0, 1.0)); // M
+        assert_eq!(const_interval!(0.0, 1.0).step(), const_interval!(0.0, 1.0)); // P0
+        assert_eq!(const_interval!(1.0, 2.0).step(), const_interval!(1.0)); // P1
+        assert_eq!(const_interval!(0.0, 0.0).step(), const_interval!(0.0, 1.0)); // Z
+        assert_eq!(const_interval!(-0.0, -0.0).step(), const_interval!(0.0, 1.0));
+        assert_eq!(I::ENTIRE.step(), const_interval!(0.0, 1.0));
+        assert_eq!(const_interval!(f64::MIN_POSITIVE, f64::INFINITY).step(), const_interval!(1.0));
+        assert!(I::EMPTY.step().is_empty());
+
+        // Unlike `step`, `sign` is -1 for negative numbers and 0 at zero.
+        assert_eq!(const_interval!(0.0).sign(), const_interval!(0.0));
+        assert_eq!(const_interval!(-2.0, -1.0).sign(), const_interval!(-1.0));
+    }
+
+    #[test]
+    fn integers() {
+        let ints = |x: I| x.integers().collect::<Vec<_>>();
+        assert_eq!(ints(const_interval!(1.5, 4.2)), vec![2, 3, 4]);
+        assert_eq!(ints(const_interval!(-2.0, 1.0)), vec![-2, -1, 0, 1]);
+        assert_eq!(ints(const_interval!(-0.5, 0.5)), vec![0]);
+        assert_eq!(ints(const_interval!(3.0)), vec![3]);
+        assert_eq!(ints(const_interval!(0.1, 0.9)), Vec::<i64>::new());
+
+        // Only the integers within the range of i64.
+        // 2^63 - 2048 ≤ x ≤ 10^300
+        let x = const_interval!(9223372036854773760.0, 1e300);
+        assert_eq!(x.integers().next(), Some(i64::MAX - 2047));
+        assert_eq!(x.integers().last(), Some(i64::MAX - 1023));
+        assert_eq!(x.integers().count(), 1025);
+        let x = const_interval!(-1e300, -9223372036854775808.0);
+        assert_eq!(ints(x), vec![i64::MIN]);
+        assert_eq!(ints(const_interval!(1e300)), Vec::<i64>::new());
+        assert_eq!(ints(const_interval!(-1e300, -1e300)), Vec::<i64>::new());
+
+        assert_eq!(I::EMPTY.integers().count(), 0);
+        assert_eq!(I::ENTIRE.integers().count(), 0);
+        assert_eq!(const_interval!(0.0, f64::INFINITY).integers().count(), 0);
+    }
+
+    #[test]
+    fn cell_range() {
+        assert_eq!(const_interval!(0.5, 3.5).cell_range(1.0), Some((0, 3)));
+        assert_eq!(const_interval!(-3.5, -0.5).cell_range(1.0), Some((-4, -1)));
+        assert_eq!(const_interval!(-0.5, 0.5).cell_range(1.0), Some((-1, 0)));
+        assert_eq!(const_interval!(-25.0, 25.0).cell_range(10.0), Some((-3, 2)));
+
+        // Points on a cell boundary belong to the cell on the right.
+        assert_eq!(const_interval!(0.0).cell_range(1.0), Some((0, 0)));
+        assert_eq!(const_interval!(-0.0).cell_range(1.0), Some((0, 0)));
+        assert_eq!(const_interval!(-2.0, 2.0).cell_range(2.0), Some((-1, 1)));
+        assert_eq!(const_interval!(0.5, 1.0).cell_range(0.25), Some((2, 4)));
+
+        // The exact quotient of the f64 numbers 0.9 and 0.1 is slightly less than 9,
+        // though it is rounded to 9 under round-to-nearest.
+        assert_eq!(const_interval!(0.9).cell_range(0.1).map(|(i, _)| i), Some(8));
+
+        assert_eq!(const_interval!(f64::MIN, f64::MAX).cell_range(1.0), Some((i64::MIN, i64::MAX)));
+
+        assert_eq!(I::EMPTY.cell_range(1.0), None);
+        assert_eq!(I::ENTIRE.cell_range(1.0), None);
+        assert_eq!(const_interval!(0.0, f64::INFINITY).cell_range(1.0), None);
+    }
+}
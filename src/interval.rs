@@ -1,9 +1,11 @@
 
+use crate::parse::format_bound;
 use std::{
     convert::TryFrom,
     error::Error,
     fmt,
     hash::{Hash, Hasher},
+    ops::RangeInclusive,
     result,
 };
 
@@ -43,13 +45,22 @@ impl Error for IntervalError {}
 pub type Result<T> = result::Result<T, IntervalError>;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Interval {
     // An interval is stored as a pair of f64
     //
     // - An nonempty interval [a, b] is stored as {inf: a, sup: b}.
     // - An empty interval is stored as {inf: NaN, sup: NaN}.
     //
+    // The layout is fixed by `repr(C)` so that a slice of intervals can be viewed
+    // as a flat array of f64 `[inf0, sup0, inf1, sup1, ...]` (see the `bytemuck` feature).
+    //
+    /// The raw lower bound, which is NaN if the interval is empty.
+    /// See [`inf()`](Interval::inf()) for the lower bound as defined by IEEE 1788.
     pub inf: f64,
+    /// The raw upper bound, which is NaN if the interval is empty.
+    /// See [`sup()`](Interval::sup()) for the upper bound as defined by IEEE 1788.
     pub sup: f64,
 }
 
@@ -72,6 +83,246 @@ impl Interval {
     pub(crate) fn zero() -> Self {
         Self { inf: 0.0, sup: 0.0 }
     }
+
+    /// Returns the lower bound of `self`.
+    ///
+    /// |      | $\self = ∅$ | $\self = \[a, b\]$ |
+    /// | ---- | ----------- | ----------------- |
+    /// | Result | $+∞$      | $a$               |
+    ///
+    /// Unlike the field [`inf`](Interval::inf), which is NaN for the empty interval,
+    /// this follows IEEE 1788. A zero lower bound is always returned as `-0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-2.0, 3.0).inf(), -2.0);
+    /// assert_eq!(Interval::EMPTY.inf(), f64::INFINITY);
+    /// assert!(Interval::EMPTY.inf.is_nan());
+    /// assert!(const_interval!(0.0, 3.0).inf().is_sign_negative());
+    /// ```
+    ///
+    /// See also: [`Interval::sup()`].
+    pub fn inf(self) -> f64 {
+        if self.is_empty() {
+            f64::INFINITY
+        } else if self.inf == 0.0 {
+            -0.0
+        } else {
+            self.inf
+        }
+    }
+
+    /// Returns the upper bound of `self`.
+    ///
+    /// |      | $\self = ∅$ | $\self = \[a, b\]$ |
+    /// | ---- | ----------- | ----------------- |
+    /// | Result | $-∞$      | $b$               |
+    ///
+    /// Unlike the field [`sup`](Interval::sup), which is NaN for the empty interval,
+    /// this follows IEEE 1788. A zero upper bound is always returned as `+0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-2.0, 3.0).sup(), 3.0);
+    /// assert_eq!(Interval::EMPTY.sup(), f64::NEG_INFINITY);
+    /// assert!(Interval::EMPTY.sup.is_nan());
+    /// assert!(const_interval!(-3.0, -0.0).sup().is_sign_positive());
+    /// ```
+    ///
+    /// See also: [`Interval::inf()`].
+    pub fn sup(self) -> f64 {
+        if self.is_empty() {
+            f64::NEG_INFINITY
+        } else if self.sup == 0.0 {
+            0.0
+        } else {
+            self.sup
+        }
+    }
+
+    /// Creates the singleton interval $\[x, x\]$, or returns `None` if `x` is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(Interval::singleton(1.5), Some(const_interval!(1.5)));
+    /// assert_eq!(Interval::singleton(f64::INFINITY), None);
+    /// assert_eq!(Interval::singleton(f64::NAN), None);
+    /// ```
+    pub fn singleton(x: f64) -> Option<Self> {
+        if x.is_finite() {
+            Some(Self::with_infsup_raw(x, x))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an interval $\[a, b\]$, returning $∅$ instead of an error
+    /// if the bounds do not form a valid interval.
+    ///
+    /// The result is $∅$ if and only if any of the following holds:
+    ///
+    /// - `a` or `b` is NaN,
+    /// - $a > b$,
+    /// - $a = +∞$ (including $\[+∞, +∞\]$, which can result from an overflow),
+    /// - $b = -∞$ (including $\[-∞, -∞\]$).
+    ///
+    /// Otherwise, the result is the same as [`interval!(a, b)`](`interval!`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(Interval::from_bounds_lossy(1.0, 2.0), const_interval!(1.0, 2.0));
+    /// assert_eq!(Interval::from_bounds_lossy(2.0, 1.0), Interval::EMPTY);
+    /// assert_eq!(Interval::from_bounds_lossy(f64::INFINITY, f64::INFINITY), Interval::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn from_bounds_lossy(a: f64, b: f64) -> Self {
+        Self::try_from((a, b)).unwrap_or(Self::EMPTY)
+    }
+
+    /// Returns `[x.next_down(), x.next_up()]`, an interval that contains every real number
+    /// that rounds to `x` under round-to-nearest.
+    ///
+    /// This is a sound enclosure of the exact result of a correctly rounded operation
+    /// that returned `x`. Since the exactness of `x` cannot be told from its value,
+    /// the result always widens `x` by one ulp on each side and is never a singleton,
+    /// even if `x` is exact, such as 0.5.
+    /// Use [`interval!(x)`](`interval!`) instead if `x` is known to be exact.
+    ///
+    /// If `x` is $±∞$, which is the result of an overflow,
+    /// the result is $\[\mathrm{MAX}, +∞\]$ or $\[-∞, -\mathrm{MAX}\]$, respectively.
+    /// If `x` is NaN, the result is $∅$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = Interval::enclose_rounded(0.1 + 0.2);
+    /// assert!(x.contains(0.1 + 0.2));
+    /// assert_eq!(x.inf, (0.1_f64 + 0.2).next_down());
+    /// assert_eq!(x.sup, (0.1_f64 + 0.2).next_up());
+    /// let x = Interval::enclose_rounded(0.5);
+    /// assert_eq!(x, interval!(0.5_f64.next_down(), 0.5_f64.next_up()).unwrap());
+    /// ```
+    #[must_use]
+    pub fn enclose_rounded(x: f64) -> Self {
+        if x.is_nan() {
+            return Self::EMPTY;
+        }
+
+        if x == f64::INFINITY {
+            Self::with_infsup_raw(f64::MAX, f64::INFINITY)
+        } else if x == f64::NEG_INFINITY {
+            Self::with_infsup_raw(f64::NEG_INFINITY, f64::MIN)
+        } else {
+            Self::with_infsup_raw(x.next_down(), x.next_up())
+        }
+    }
+
+    /// Returns the tightest interval that contains all of `points`.
+    ///
+    /// This is the typical way to bound a function from its values at the endpoints
+    /// and the interior critical points of the domain.
+    /// If `rounded` is `true`, each point is assumed to be the result of a correctly rounded
+    /// computation and is enclosed by [`Interval::enclose_rounded`];
+    /// otherwise, the finite points are taken as exact.
+    /// Infinite points are always enclosed as in [`Interval::enclose_rounded`],
+    /// and NaN points are ignored. If no points remain, the result is $∅$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(Interval::from_candidates(&[2.0, -1.0, 0.5], false), const_interval!(-1.0, 2.0));
+    /// let x = Interval::from_candidates(&[2.0, -1.0, 0.5], true);
+    /// assert!(x.inf < -1.0 && 2.0 < x.sup);
+    /// assert_eq!(Interval::from_candidates(&[], false), Interval::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn from_candidates(points: &[f64], rounded: bool) -> Self {
+        points.iter().fold(Self::EMPTY, |hull, &p| {
+            let x = if rounded || !p.is_finite() {
+                Self::enclose_rounded(p)
+            } else {
+                Self::with_infsup_raw(p, p)
+            };
+            hull.convex_hull(x)
+        })
+    }
+
+    /// Returns `self` with the signs of zero bounds normalized:
+    /// a zero lower bound becomes `-0.0` and a zero upper bound becomes `+0.0`.
+    ///
+    /// Since `-0.0 == +0.0`, this never changes the set of real numbers that `self` represents,
+    /// nor the result of any operation that compares the bounds (such as [`Interval::abs`] or
+    /// [`Interval::sign`]). It only fixes the bit patterns, so that each interval with
+    /// zero bounds has a single representation, which matches the convention of
+    /// IEEE 1788 for reporting the bounds of an interval. The empty interval is returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(0.0, -0.0).canonicalize_zeros();
+    /// assert!(x.inf.is_sign_negative() && x.sup.is_sign_positive());
+    /// ```
+    #[must_use]
+    pub fn canonicalize_zeros(self) -> Self {
+        Self {
+            inf: if self.inf == 0.0 { -0.0 } else { self.inf },
+            sup: if self.sup == 0.0 { 0.0 } else { self.sup },
+        }
+    }
+
+    /// Unpacks intervals from a flat array of bounds `[inf0, sup0, inf1, sup1, ...]`.
+    ///
+    /// This is the layout of a JavaScript `Float64Array` passed across the WASM boundary.
+    /// Each pair is converted by [`Interval::from_bounds_lossy`], so a pair of NaNs,
+    /// which is how [`Interval::to_flat`] encodes $∅$, as well as any other pair
+    /// that does not form a valid interval, becomes $∅$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` is odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let xs = Interval::from_flat(&[1.0, 2.0, f64::NAN, f64::NAN]);
+    /// assert_eq!(xs, vec![const_interval!(1.0, 2.0), Interval::EMPTY]);
+    /// ```
+    #[must_use]
+    pub fn from_flat(data: &[f64]) -> Vec<Interval> {
+        assert!(data.len().is_multiple_of(2), "the length of `data` must be even");
+        data.chunks_exact(2)
+            .map(|ab| Self::from_bounds_lossy(ab[0], ab[1]))
+            .collect()
+    }
+
+    /// Packs `intervals` into a flat array of bounds `[inf0, sup0, inf1, sup1, ...]`.
+    ///
+    /// $∅$ is encoded as a pair of NaNs. The result can be read back by [`Interval::from_flat`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let fs = Interval::to_flat(&[const_interval!(1.0, 2.0), Interval::EMPTY]);
+    /// assert_eq!(&fs[..2], &[1.0, 2.0]);
+    /// assert!(fs[2].is_nan() && fs[3].is_nan());
+    /// ```
+    #[must_use]
+    pub fn to_flat(intervals: &[Interval]) -> Vec<f64> {
+        intervals.iter().flat_map(|x| [x.inf, x.sup]).collect()
+    }
 }
 
 impl PartialEq for Interval {
@@ -84,17 +335,281 @@ impl Eq for Interval {}
 
 impl Hash for Interval {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.inf.to_bits().hash(state);
-        self.sup.to_bits().hash(state);
+        // Must be consistent with `eq`: all empty intervals are equal, and so are
+        // the bounds `-0.0` and `+0.0`. Adding `0.0` turns `-0.0` into `+0.0`.
+        if self.is_empty() {
+            f64::NAN.to_bits().hash(state);
+            f64::NAN.to_bits().hash(state);
+        } else {
+            (self.inf + 0.0).to_bits().hash(state);
+            (self.sup + 0.0).to_bits().hash(state);
+        }
     }
 }
 
+// Returns `true` if `x` looks like an exact value rather than the result of rounding,
+// i.e., it is not finite or its shortest round-trip decimal representation has at most
+// 15 significant digits. Every decimal number with at most 15 significant digits survives
+// the round trip through `f64`, while rounded results typically need 16 or 17.
+fn looks_exact(x: f64) -> bool {
+    if !x.is_finite() {
+        return true;
+    }
+
+    let s = format!("{:e}", x);
+    let mantissa = s.split('e').next().unwrap();
+    mantissa.chars().filter(|c| c.is_ascii_digit()).count() <= 15
+}
+
+/// Formats the interval as `[a, b]`, or `[empty]` or `[entire]` for $∅$ or $\R$,
+/// following the text representation of IEEE 1788.
+///
+/// The precision, such as in `{:.3}`, is applied to each bound, which is rounded outward,
+/// so that the printed interval still encloses `self`.
+///
+/// With the alternate flag (`{:#}`), each bound that does not look like an exact value
+/// is followed by `~`. A bound is considered exact-looking if it is infinite or its shortest
+/// decimal representation that round-trips has at most 15 significant digits,
+/// such as `1`, `0.1` or `1.5e10`. This is only a heuristic for spotting
+/// bounds produced by rounding, such as `0.30000000000000004`, since the provenance of
+/// a bound is not recorded.
+///
+/// ```
+/// use inari_wasm::*;
+/// let x = interval!(0.1, 0.1 + 0.2).unwrap();
+/// assert_eq!(format!("{}", x), "[0.1, 0.30000000000000004]");
+/// assert_eq!(format!("{:#}", x), "[0.1, 0.30000000000000004~]");
+/// assert_eq!(format!("{:.3}", x), "[0.100, 0.301]");
+/// assert_eq!(format!("{}", Interval::EMPTY), "[empty]");
+/// assert_eq!(format!("{}", Interval::ENTIRE), "[entire]");
+/// ```
 impl fmt::Display for Interval {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[{}, {}]", self.inf, self.sup)
+        if self.is_empty() {
+            return write!(f, "[empty]");
+        }
+        if self.is_entire() {
+            return write!(f, "[entire]");
+        }
+
+        let mark = |x: f64| if f.alternate() && !looks_exact(x) { "~" } else { "" };
+        match f.precision() {
+            Some(p) => {
+                let (a, b) = (format_bound(self.inf, p, false), format_bound(self.sup, p, true));
+                write!(f, "[{}{}, {}{}]", a, mark(self.inf), b, mark(self.sup))
+            }
+            None => write!(f, "[{}{}, {}{}]", self.inf, mark(self.inf), self.sup, mark(self.sup)),
+        }
     }
 }
 
+impl Interval {
+    /// Returns a JSON object representing `self`, suitable for plotting libraries.
+    ///
+    /// - A nonempty interval $\[a, b\]$ is written as `{"lo": a, "hi": b}`.
+    ///   Since JSON has no representation of infinities, $±∞$ are written
+    ///   as the strings `"Infinity"` and `"-Infinity"`, which JavaScript's `Number()` accepts.
+    /// - The empty interval is written as `{"empty": true}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(1.0, 2.5).to_plot_json(), r#"{"lo": 1, "hi": 2.5}"#);
+    /// assert_eq!(Interval::ENTIRE.to_plot_json(), r#"{"lo": "-Infinity", "hi": "Infinity"}"#);
+    /// assert_eq!(Interval::EMPTY.to_plot_json(), r#"{"empty": true}"#);
+    /// ```
+    pub fn to_plot_json(self) -> String {
+        fn num(x: f64) -> String {
+            if x == f64::INFINITY {
+                "\"Infinity\"".to_string()
+            } else if x == f64::NEG_INFINITY {
+                "\"-Infinity\"".to_string()
+            } else {
+                x.to_string()
+            }
+        }
+
+        if self.is_empty() {
+            return "{\"empty\": true}".to_string();
+        }
+
+        format!("{{\"lo\": {}, \"hi\": {}}}", num(self.inf), num(self.sup))
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl Interval {
+    /// Returns a key for storing `self` in ordered containers such as [`BTreeMap`](std::collections::BTreeMap).
+    ///
+    /// A nonempty interval $\[a, b\]$ is mapped to `(a, b)`, so the keys are ordered
+    /// lexicographically by the lower bounds and then by the upper bounds.
+    /// Thus, the intervals unbounded below come first, and among them,
+    /// $\[-∞, b\]$ with finite $b$ precede $\[-∞, +∞\]$.
+    /// The empty interval is mapped to `(+∞, +∞)`, which is greater than the key of any nonempty interval.
+    ///
+    /// Two intervals have the same key if and only if they are equal.
+    /// In particular, `-0.0` and `+0.0` are not distinguished.
+    ///
+    /// Requires the `ordered-float` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// use std::collections::BTreeSet;
+    /// let set: BTreeSet<_> = [Interval::EMPTY, const_interval!(1.0, 2.0), Interval::ENTIRE]
+    ///     .iter()
+    ///     .map(|x| x.as_ordered_key())
+    ///     .collect();
+    /// assert_eq!(set.iter().next(), Some(&Interval::ENTIRE.as_ordered_key()));
+    /// assert_eq!(set.last(), Some(&Interval::EMPTY.as_ordered_key()));
+    /// ```
+    pub fn as_ordered_key(self) -> (ordered_float::OrderedFloat<f64>, ordered_float::OrderedFloat<f64>) {
+        use ordered_float::OrderedFloat;
+
+        if self.is_empty() {
+            return (OrderedFloat(f64::INFINITY), OrderedFloat(f64::INFINITY));
+        }
+
+        (OrderedFloat(self.inf), OrderedFloat(self.sup))
+    }
+}
+
+/// Serializes the interval as a two-element sequence `[inf, sup]`.
+///
+/// An infinite bound is serialized as a none value (`null` in JSON), since JSON and other formats
+/// cannot represent infinities. Its sign is determined by the position, so that
+/// $\[-∞, 1\]$ is `[null, 1.0]` and $\R$ is `[null, null]`.
+/// The empty interval is serialized as the one-element sequence `["empty"]`.
+///
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Interval {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        if self.is_empty() {
+            let mut seq = serializer.serialize_seq(Some(1))?;
+            seq.serialize_element("empty")?;
+            return seq.end();
+        }
+
+        let finite = |x: f64| if x.is_finite() { Some(x) } else { None };
+        let mut seq = serializer.serialize_seq(Some(2))?;
+        seq.serialize_element(&finite(self.inf))?;
+        seq.serialize_element(&finite(self.sup))?;
+        seq.end()
+    }
+}
+
+/// Deserializes an interval from the representation described in the [`Serialize`](serde::Serialize) impl.
+///
+/// Bounds that do not form a valid interval, such as `[2.0, 1.0]`, result in an error.
+///
+/// Since an element of the sequence can be a number, a none value or a string,
+/// the type of each element is not known in advance, and it is deserialized with
+/// [`deserialize_any`](serde::Deserializer::deserialize_any).
+/// Therefore, only self-describing formats such as JSON are supported;
+/// formats that are not, such as bincode, fail to deserialize intervals.
+///
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Interval {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        use serde::de::{self, Deserializer, SeqAccess, Visitor};
+
+        // An element of the sequence: either a bound or the marker of the empty interval.
+        enum Element {
+            Empty,
+            Bound(Option<f64>),
+        }
+
+        impl<'de> serde::Deserialize<'de> for Element {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+                struct ElementVisitor;
+
+                impl<'de> Visitor<'de> for ElementVisitor {
+                    type Value = Element;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "a number, null or \"empty\"")
+                    }
+
+                    fn visit_str<E: de::Error>(self, v: &str) -> result::Result<Element, E> {
+                        match v {
+                            "empty" => Ok(Element::Empty),
+                            _ => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+                        }
+                    }
+
+                    fn visit_f64<E: de::Error>(self, v: f64) -> result::Result<Element, E> {
+                        Ok(Element::Bound(Some(v)))
+                    }
+
+                    fn visit_i64<E: de::Error>(self, v: i64) -> result::Result<Element, E> {
+                        Ok(Element::Bound(Some(v as f64)))
+                    }
+
+                    fn visit_u64<E: de::Error>(self, v: u64) -> result::Result<Element, E> {
+                        Ok(Element::Bound(Some(v as f64)))
+                    }
+
+                    fn visit_none<E: de::Error>(self) -> result::Result<Element, E> {
+                        Ok(Element::Bound(None))
+                    }
+
+                    fn visit_unit<E: de::Error>(self) -> result::Result<Element, E> {
+                        Ok(Element::Bound(None))
+                    }
+
+                    fn visit_some<D: Deserializer<'de>>(self, d: D) -> result::Result<Element, D::Error> {
+                        d.deserialize_any(self)
+                    }
+                }
+
+                deserializer.deserialize_any(ElementVisitor)
+            }
+        }
+
+        struct IntervalVisitor;
+
+        impl<'de> Visitor<'de> for IntervalVisitor {
+            type Value = Interval;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a sequence [inf, sup] or [\"empty\"]")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> result::Result<Interval, A::Error> {
+                let a = match seq.next_element::<Element>()? {
+                    Some(Element::Empty) => {
+                        if seq.next_element::<Element>()?.is_some() {
+                            return Err(de::Error::invalid_length(2, &self));
+                        }
+                        return Ok(Interval::EMPTY);
+                    }
+                    Some(Element::Bound(a)) => a.unwrap_or(f64::NEG_INFINITY),
+                    None => return Err(de::Error::invalid_length(0, &self)),
+                };
+                let b = match seq.next_element::<Element>()? {
+                    Some(Element::Bound(b)) => b.unwrap_or(f64::INFINITY),
+                    Some(Element::Empty) => {
+                        return Err(de::Error::invalid_value(de::Unexpected::Str("empty"), &self))
+                    }
+                    None => return Err(de::Error::invalid_length(1, &self)),
+                };
+                if seq.next_element::<Element>()?.is_some() {
+                    return Err(de::Error::invalid_length(3, &self));
+                }
+
+                Interval::try_from((a, b)).map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_seq(IntervalVisitor)
+    }
+}
 
 impl TryFrom<(f64, f64)> for Interval {
     type Error = IntervalError;
@@ -110,6 +625,43 @@ impl TryFrom<(f64, f64)> for Interval {
     }
 }
 
+/// Creates the singleton interval $\[x, x\]$.
+///
+/// The result is $∅$ if `x` is NaN or infinite, since no real number lies at $±∞$.
+///
+/// # Examples
+///
+/// ```
+/// use inari_wasm::*;
+/// assert_eq!(Interval::from(1.5), const_interval!(1.5, 1.5));
+/// assert_eq!(Interval::from(f64::NAN), Interval::EMPTY);
+/// ```
+impl From<f64> for Interval {
+    fn from(x: f64) -> Self {
+        Self::from_bounds_lossy(x, x)
+    }
+}
+
+/// Creates the interval $\[a, b\]$ from the range `a..=b`
+/// with the same conditions as the conversion from `(a, b)`.
+///
+/// # Examples
+///
+/// ```
+/// use inari_wasm::*;
+/// use std::convert::TryInto;
+/// let x: Interval = (1.0..=2.0).try_into().unwrap();
+/// assert_eq!(x, const_interval!(1.0, 2.0));
+/// assert!(Interval::try_from(2.0..=1.0).is_err());
+/// ```
+impl TryFrom<RangeInclusive<f64>> for Interval {
+    type Error = IntervalError;
+
+    fn try_from(r: RangeInclusive<f64>) -> Result<Self> {
+        Self::try_from(r.into_inner())
+    }
+}
+
 
 // macros
 
@@ -162,6 +714,58 @@ macro_rules! const_interval {
 mod tests {
     use crate::*;
 
+    #[test]
+    fn inf_sup() {
+        let x = const_interval!(-2.0, 3.0);
+        assert_eq!((x.inf(), x.sup()), (x.inf, x.sup));
+
+        // The empty interval.
+        let e = Interval::EMPTY;
+        assert!(e.inf.is_nan() && e.sup.is_nan());
+        assert_eq!((e.inf(), e.sup()), (f64::INFINITY, f64::NEG_INFINITY));
+
+        // Signed zeros.
+        for x in [const_interval!(0.0), const_interval!(-0.0, 0.0), const_interval!(0.0, -0.0)] {
+            assert!(x.inf() == 0.0 && x.inf().is_sign_negative());
+            assert!(x.sup() == 0.0 && x.sup().is_sign_positive());
+        }
+
+        let x = Interval::ENTIRE;
+        assert_eq!((x.inf(), x.sup()), (f64::NEG_INFINITY, f64::INFINITY));
+
+        assert_eq!(Interval::singleton(-0.5), Some(const_interval!(-0.5)));
+        assert_eq!(Interval::singleton(f64::MAX), Some(const_interval!(f64::MAX)));
+        assert_eq!(Interval::singleton(f64::NEG_INFINITY), None);
+        assert_eq!(Interval::singleton(f64::NAN), None);
+    }
+
+    #[test]
+    fn conversions() {
+        use std::convert::TryInto;
+
+        assert_eq!(Interval::from(1.5), const_interval!(1.5));
+        assert_eq!(Interval::from(-0.0), const_interval!(0.0));
+        let x: Interval = 0.1.into();
+        assert!(x.is_singleton() && x.contains(0.1));
+        assert_eq!(Interval::from(f64::NAN), Interval::EMPTY);
+        assert_eq!(Interval::from(f64::INFINITY), Interval::EMPTY);
+        assert_eq!(Interval::from(f64::NEG_INFINITY), Interval::EMPTY);
+
+        let x: Interval = (1.0..=2.0).try_into().unwrap();
+        assert_eq!(x, const_interval!(1.0, 2.0));
+        assert_eq!(Interval::try_from(3.0..=3.0).unwrap(), const_interval!(3.0));
+        assert_eq!(
+            Interval::try_from(f64::NEG_INFINITY..=f64::INFINITY).unwrap(),
+            Interval::ENTIRE
+        );
+        for r in [2.0..=1.0, f64::NAN..=1.0, f64::INFINITY..=f64::INFINITY] {
+            assert_eq!(
+                Interval::try_from(r).unwrap_err().kind(),
+                IntervalErrorKind::UndefinedOperation
+            );
+        }
+    }
+
     #[test]
     fn main() {
         let x = const_interval!(-1.2, 1.2);
@@ -173,20 +777,376 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "benchmark; run with `cargo test --release -- --ignored`"]
     pub fn benchmark(){
-        let N = 1_000_000;
-        let x = const_interval!(10.0, 20.0);
-        let y = const_interval!(10.0, 20.0);
-    
-        let mut sum = 0.0;
-        // time 
-        let start = std::time::Instant::now();
-        for _ in 0..N {
-            let r = x.powi(2) + y.powi(2) + const_interval!(3.0) * (const_interval!(10.0) * x.powi(3)).sin() - const_interval!(1.0);
-            sum += r.inf;
-        }
-        let duration = start.elapsed();
-        println!("The loop took: {:?}", duration);
+        let n = 1_000_000;
+
+        for (x, y) in [
+            (const_interval!(10.0, 20.0), const_interval!(10.0, 20.0)),
+            (const_interval!(-0.5, 0.5), const_interval!(-0.5, 0.5)),
+        ] {
+            let mut sum = 0.0;
+            // time 
+            let start = std::time::Instant::now();
+            for _ in 0..n {
+                let (x, y) = std::hint::black_box((x, y));
+                let r = x.powi(2) + y.powi(2) + const_interval!(3.0) * (const_interval!(10.0) * x.powi(3)).sin() - const_interval!(1.0);
+                sum += r.inf;
+            }
+            let duration = start.elapsed();
+            println!("The loop took: {:?} (x = {}, sum = {})", duration, x, sum);
+
+            // The powers alone, which are otherwise hidden behind the cost of `sin`.
+            let mut sum = 0.0;
+            let start = std::time::Instant::now();
+            for _ in 0..n {
+                let (x, y) = std::hint::black_box((x, y));
+                let r = x.powi(2) + y.powi(2) + x.powi(3);
+                sum += r.inf;
+            }
+            let duration = start.elapsed();
+            println!("The powers took: {:?} (x = {}, sum = {})", duration, x, sum);
+        }
+    }
+
+    #[test]
+    fn canonicalize_zeros() {
+        const Z: [f64; 2] = [-0.0, 0.0];
+        for a in Z {
+            for b in Z {
+                let x = Interval::try_from((a, b)).unwrap();
+                let y = x.canonicalize_zeros();
+                assert!(y.inf.is_sign_negative() && y.sup.is_sign_positive());
+                assert_eq!(x, y);
+                assert_eq!(x.classify(), y.classify());
+                assert_eq!(x.abs(), y.abs());
+                assert_eq!(x.sign(), const_interval!(0.0));
+                assert_eq!(y.sign(), const_interval!(0.0));
+            }
+
+            let x = Interval::try_from((a, 1.0)).unwrap();
+            let y = x.canonicalize_zeros();
+            assert!(y.inf.is_sign_negative() && y.sup == 1.0);
+            assert_eq!(x.classify(), y.classify());
+            assert_eq!(x.abs(), y.abs());
+            assert_eq!(x.sign(), const_interval!(1.0));
+            assert_eq!(y.sign(), const_interval!(1.0));
+
+            let x = Interval::try_from((-1.0, a)).unwrap();
+            let y = x.canonicalize_zeros();
+            assert!(y.inf == -1.0 && y.sup.is_sign_positive());
+            assert_eq!(x.classify(), y.classify());
+            assert_eq!(x.abs(), y.abs());
+            assert_eq!(x.sign(), const_interval!(-1.0));
+            assert_eq!(y.sign(), const_interval!(-1.0));
+        }
+
+        assert!(Interval::EMPTY.canonicalize_zeros().is_empty());
+        assert_eq!(Interval::ENTIRE.canonicalize_zeros(), Interval::ENTIRE);
+    }
+
+    #[test]
+    fn from_bounds_lossy() {
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        assert_eq!(Interval::from_bounds_lossy(-1.0, 1.0), const_interval!(-1.0, 1.0));
+        assert_eq!(Interval::from_bounds_lossy(1.0, 1.0), const_interval!(1.0));
+        assert_eq!(Interval::from_bounds_lossy(-INF, 0.0), const_interval!(f64::NEG_INFINITY, 0.0));
+        assert_eq!(Interval::from_bounds_lossy(0.0, INF), const_interval!(0.0, f64::INFINITY));
+        assert_eq!(Interval::from_bounds_lossy(-INF, INF), Interval::ENTIRE);
+
+        for (a, b) in [
+            (1.0, -1.0),
+            (INF, INF),
+            (-INF, -INF),
+            (INF, -INF),
+            (NAN, 1.0),
+            (1.0, NAN),
+            (NAN, NAN),
+        ] {
+            assert!(Interval::from_bounds_lossy(a, b).is_empty());
+            assert!(Interval::try_from((a, b)).is_err());
+        }
+    }
+
+    #[test]
+    fn flat_round_trip() {
+        let xs = vec![
+            const_interval!(1.0, 2.0),
+            Interval::EMPTY,
+            Interval::ENTIRE,
+            const_interval!(-0.0, 0.0),
+            const_interval!(f64::NEG_INFINITY, -1.0),
+        ];
+        let fs = Interval::to_flat(&xs);
+        assert_eq!(fs.len(), 10);
+        assert!(fs[2].is_nan() && fs[3].is_nan());
+        assert_eq!(Interval::from_flat(&fs), xs);
+
+        assert!(Interval::to_flat(&[]).is_empty());
+        assert!(Interval::from_flat(&[]).is_empty());
+
+        // Invalid pairs become empty.
+        let ys = Interval::from_flat(&[2.0, 1.0, f64::NAN, 1.0, f64::INFINITY, f64::INFINITY]);
+        assert!(ys.iter().all(|y| y.is_empty()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_flat_odd_length() {
+        let _ = Interval::from_flat(&[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn eq_unbounded() {
+        const INF: f64 = f64::INFINITY;
+        let xs = [
+            const_interval!(f64::NEG_INFINITY, 0.0),
+            const_interval!(0.0, INF),
+            const_interval!(f64::NEG_INFINITY, 1.0),
+            const_interval!(-1.0, INF),
+            const_interval!(-1.0, 1.0),
+            Interval::ENTIRE,
+            Interval::EMPTY,
+        ];
+        for (i, x) in xs.iter().enumerate() {
+            for (j, y) in xs.iter().enumerate() {
+                assert_eq!(x == y, i == j, "{} == {}", x, y);
+            }
+        }
+
+        assert_eq!(Interval::ENTIRE, interval!(f64::NEG_INFINITY, INF).unwrap());
+        assert_ne!(Interval::ENTIRE, const_interval!(f64::NEG_INFINITY, 0.0));
+        assert_ne!(Interval::ENTIRE, const_interval!(0.0, INF));
+    }
+
+    #[test]
+    fn eq_signed_zeros() {
+        use std::{
+            collections::hash_map::DefaultHasher,
+            hash::{Hash, Hasher},
+        };
+
+        fn hash(x: Interval) -> u64 {
+            let mut h = DefaultHasher::new();
+            x.hash(&mut h);
+            h.finish()
+        }
+
+        let pairs = [
+            (const_interval!(-0.0, 1.0), const_interval!(0.0, 1.0)),
+            (const_interval!(-1.0, -0.0), const_interval!(-1.0, 0.0)),
+            (const_interval!(-0.0, -0.0), const_interval!(0.0, 0.0)),
+            (const_interval!(-0.0, f64::INFINITY), const_interval!(0.0, f64::INFINITY)),
+            (Interval::EMPTY, Interval::with_infsup_raw(f64::NAN, -f64::NAN)),
+        ];
+        for (x, y) in pairs {
+            assert_eq!(x, y);
+            assert_eq!(hash(x), hash(y));
+        }
+    }
+
+    #[test]
+    fn from_candidates() {
+        // x^3 - 3x on [-2, 1.5]: the endpoints and the critical points ±1.
+        let f = |x: f64| x * x * x - 3.0 * x;
+        let points = [f(-2.0), f(-1.0), f(1.0), f(1.5)];
+
+        let y = Interval::from_candidates(&points, false);
+        assert_eq!(y, const_interval!(-2.0, 2.0));
+
+        let y = Interval::from_candidates(&points, true);
+        assert!(y.inf < -2.0 && 2.0 < y.sup);
+        assert_eq!(y, Interval::enclose_rounded(-2.0).convex_hull(Interval::enclose_rounded(2.0)));
+
+        assert_eq!(
+            Interval::from_candidates(&[1.0, f64::NAN, f64::INFINITY], false),
+            interval!(1.0, f64::INFINITY).unwrap()
+        );
+        assert!(Interval::from_candidates(&[f64::NAN], true).is_empty());
+        assert!(Interval::from_candidates(&[], true).is_empty());
+    }
+
+    #[test]
+    fn enclose_rounded() {
+        let x = Interval::enclose_rounded(1.0);
+        assert_eq!(x, interval!(1.0 - f64::EPSILON / 2.0, 1.0 + f64::EPSILON).unwrap());
+        assert!(x.contains(1.0));
+
+        let x = Interval::enclose_rounded(0.0);
+        assert!(x.contains(0.0) && x.inf < 0.0 && 0.0 < x.sup);
+        assert_eq!(x.sup, f64::from_bits(1));
+
+        // 1/3 rounded to nearest.
+        let x = Interval::enclose_rounded(1.0 / 3.0);
+        assert!(x.inf < 1.0 / 3.0 && 1.0 / 3.0 < x.sup);
+        assert!(!x.is_singleton());
+
+        assert_eq!(
+            Interval::enclose_rounded(f64::INFINITY),
+            interval!(f64::MAX, f64::INFINITY).unwrap()
+        );
+        assert_eq!(
+            Interval::enclose_rounded(f64::NEG_INFINITY),
+            interval!(f64::NEG_INFINITY, f64::MIN).unwrap()
+        );
+        assert_eq!(
+            Interval::enclose_rounded(f64::MAX),
+            interval!(f64::MAX.next_down(), f64::INFINITY).unwrap()
+        );
+        assert!(Interval::enclose_rounded(f64::NAN).is_empty());
+    }
+
+    #[test]
+    fn display_alternate() {
+        assert_eq!(format!("{:#}", const_interval!(1.0, 2.5)), "[1, 2.5]");
+        assert_eq!(format!("{:#}", const_interval!(-0.1, 1.5e10)), "[-0.1, 15000000000]");
+        assert_eq!(format!("{:#}", Interval::ENTIRE), "[entire]");
+        assert_eq!(format!("{:#}", Interval::EMPTY), "[empty]");
+        assert_eq!(format!("{:#}", const_interval!(f64::NEG_INFINITY, 0.0)), "[-inf, 0]");
+        assert_eq!(format!("{:#}", const_interval!(123456789012345.0)), "[123456789012345, 123456789012345]");
+
+        let x = interval!(1.0 / 3.0, 2.0).unwrap();
+        assert_eq!(format!("{:#}", x), "[0.3333333333333333~, 2]");
+        let x = interval!(-std::f64::consts::PI, f64::INFINITY).unwrap();
+        assert_eq!(format!("{:#}", x), "[-3.141592653589793~, inf]");
+        let x = Interval::enclose_rounded(0.1);
+        assert_eq!(format!("{:#}", x), format!("[{}~, {}~]", x.inf, x.sup));
+
+        // The default format is unaffected.
+        assert_eq!(format!("{}", interval!(1.0 / 3.0, 2.0).unwrap()), "[0.3333333333333333, 2]");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Interval::EMPTY), "[empty]");
+        assert_eq!(format!("{}", Interval::ENTIRE), "[entire]");
+        assert_eq!(format!("{}", const_interval!(-1.5, 2.0)), "[-1.5, 2]");
+        assert_eq!(format!("{}", const_interval!(0.0, f64::INFINITY)), "[0, inf]");
+        assert_eq!(format!("{}", const_interval!(f64::NEG_INFINITY, 0.0)), "[-inf, 0]");
+
+        assert_eq!(format!("{:.3}", const_interval!(-1.5, 2.0)), "[-1.500, 2.000]");
+        assert_eq!(format!("{:.0}", const_interval!(0.25, 1.75)), "[0, 2]");
+        assert_eq!(format!("{:.0}", const_interval!(0.75, 1.25)), "[0, 2]");
+        assert_eq!(format!("{:.0}", const_interval!(-1.25, -0.75)), "[-2, -0]");
+        assert_eq!(format!("{:.1}", const_interval!(-9.96, 9.96)), "[-10.0, 10.0]");
+        assert_eq!(format!("{:.2}", const_interval!(0.1, 0.1)), "[0.10, 0.11]");
+        assert_eq!(format!("{:.2}", const_interval!(-0.1, -0.1)), "[-0.11, -0.10]");
+        assert_eq!(format!("{:.2}", const_interval!(-0.001, 0.001)), "[-0.01, 0.01]");
+        assert_eq!(format!("{:.3}", Interval::EMPTY), "[empty]");
+        assert_eq!(format!("{:.3}", Interval::ENTIRE), "[entire]");
+        assert_eq!(format!("{:#.2}", interval!(1.0 / 3.0, 2.0).unwrap()), "[0.33~, 2.00]");
+    }
+
+    #[test]
+    fn to_plot_json() {
+        assert_eq!(const_interval!(-1.0, 0.1).to_plot_json(), r#"{"lo": -1, "hi": 0.1}"#);
+        assert_eq!(const_interval!(-0.0, 0.0).to_plot_json(), r#"{"lo": -0, "hi": 0}"#);
+        assert_eq!(const_interval!(1e-300, 1e300).to_plot_json(), format!(r#"{{"lo": {}, "hi": {}}}"#, 1e-300, 1e300));
+        assert_eq!(
+            const_interval!(0.0, f64::INFINITY).to_plot_json(),
+            r#"{"lo": 0, "hi": "Infinity"}"#
+        );
+        assert_eq!(
+            const_interval!(f64::NEG_INFINITY, 0.0).to_plot_json(),
+            r#"{"lo": "-Infinity", "hi": 0}"#
+        );
+        assert_eq!(Interval::ENTIRE.to_plot_json(), r#"{"lo": "-Infinity", "hi": "Infinity"}"#);
+        assert_eq!(Interval::EMPTY.to_plot_json(), r#"{"empty": true}"#);
+
+        // The bounds round-trip through their decimal representation.
+        let x = const_interval!(0.1, 0.30000000000000004);
+        let s = x.to_plot_json();
+        let lo = s[7..s.find(',').unwrap()].parse::<f64>().unwrap();
+        let hi = s[s.rfind(' ').unwrap() + 1..s.len() - 1].parse::<f64>().unwrap();
+        assert_eq!(Interval::try_from((lo, hi)).unwrap(), x);
+    }
+
+    #[cfg(feature = "ordered-float")]
+    #[test]
+    fn as_ordered_key() {
+        let mut xs = vec![
+            Interval::EMPTY,
+            const_interval!(1.0, 2.0),
+            const_interval!(1.0, f64::INFINITY),
+            Interval::ENTIRE,
+            const_interval!(f64::NEG_INFINITY, 0.0),
+            const_interval!(-1.0, 2.0),
+            const_interval!(1.0, 1.5),
+        ];
+        xs.sort_by_key(|x| x.as_ordered_key());
+        assert_eq!(
+            xs,
+            vec![
+                const_interval!(f64::NEG_INFINITY, 0.0),
+                Interval::ENTIRE,
+                const_interval!(-1.0, 2.0),
+                const_interval!(1.0, 1.5),
+                const_interval!(1.0, 2.0),
+                const_interval!(1.0, f64::INFINITY),
+                Interval::EMPTY,
+            ]
+        );
+
+        assert_eq!(const_interval!(-0.0, 0.0).as_ordered_key(), const_interval!(0.0, -0.0).as_ordered_key());
+        assert_eq!(Interval::EMPTY.as_ordered_key(), Interval::with_infsup_raw(f64::NAN, -f64::NAN).as_ordered_key());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let cases = [
+            (const_interval!(1.0, 2.5), "[1.0,2.5]"),
+            (const_interval!(-0.1, 0.0), "[-0.1,0.0]"),
+            (const_interval!(f64::NEG_INFINITY, 3.0), "[null,3.0]"),
+            (const_interval!(-3.0, f64::INFINITY), "[-3.0,null]"),
+            (Interval::ENTIRE, "[null,null]"),
+            (Interval::EMPTY, r#"["empty"]"#),
+        ];
+        for (x, json) in cases {
+            assert_eq!(serde_json::to_string(&x).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Interval>(json).unwrap(), x);
+        }
+
+        let xs = vec![const_interval!(0.1, 0.3), Interval::EMPTY, Interval::ENTIRE];
+        let json = serde_json::to_string(&xs).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Interval>>(&json).unwrap(), xs);
+
+        assert_eq!(serde_json::from_str::<Interval>("[1, 2]").unwrap(), const_interval!(1.0, 2.0));
+        for json in [
+            "[2.0, 1.0]",
+            "[1.0]",
+            "[]",
+            "[1.0, 2.0, 3.0]",
+            r#"["empty", 1.0]"#,
+            r#"[1.0, "empty"]"#,
+            r#"["full"]"#,
+            "1.0",
+        ] {
+            assert!(serde_json::from_str::<Interval>(json).is_err(), "{}", json);
+        }
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(std::mem::size_of::<Interval>(), 16);
+        assert_eq!(std::mem::align_of::<Interval>(), std::mem::align_of::<f64>());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_round_trip() {
+        let xs = [const_interval!(1.0, 2.0), Interval::EMPTY, Interval::ENTIRE];
+        let bytes: &[u8] = bytemuck::cast_slice(&xs);
+        assert_eq!(bytes.len(), 48);
+
+        let ys: &[Interval] = bytemuck::cast_slice(bytes);
+        assert_eq!(ys, &xs);
+
+        let fs: &[f64] = bytemuck::cast_slice(&xs);
+        assert_eq!(&fs[..2], &[1.0, 2.0]);
+        assert!(fs[2].is_nan() && fs[3].is_nan());
     }
 
     #[test]
@@ -1,13 +1,34 @@
 pub use self::{
-	interval::{Interval, IntervalError, IntervalErrorKind}
+	classify::{IntervalClass, IntervalClass2, SpecialState},
+	decoration::{DecInterval, Decoration},
+	elementary::DomainStatus,
+	geometry::Box2,
+	interval::{Interval, IntervalError, IntervalErrorKind},
+	overlap::OverlappingState
 };
 
+#[cfg(feature = "wasm")]
+pub use self::wasm::WasmInterval;
+
 mod interval;
 mod boolean;
 mod arith;
 mod classify;
 mod constants;
+mod decoration;
 mod absmax;
 mod elementary;
 mod set_op;
-mod integer;
\ No newline at end of file
+mod overlap;
+mod integer;
+mod geometry;
+mod parse;
+mod solve;
+mod linalg;
+mod stats;
+mod poly;
+mod numeric;
+#[cfg(test)]
+mod test_util;
+#[cfg(feature = "wasm")]
+mod wasm;
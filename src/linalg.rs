@@ -0,0 +1,58 @@
+use crate::interval::*;
+
+impl Interval {
+    /// Returns the range of the ∞-norm (the maximum absolute row sum)
+    ///
+    /// $$
+    /// ‖A‖_∞ = \max_i \sum_j |a_{ij}|
+    /// $$
+    ///
+    /// over all real matrices $A$ whose entries $a_{ij}$ are taken from `rows[i][j]`.
+    ///
+    /// The rows may have different lengths. The result is $\[0, 0\]$ if there are no rows,
+    /// and $∅$ if any entry is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let rows = vec![
+    ///     vec![const_interval!(1.0, 2.0), const_interval!(-3.0, -1.0)],
+    ///     vec![const_interval!(-1.0, 1.0), const_interval!(0.5)],
+    /// ];
+    /// assert_eq!(Interval::inf_norm_rows(&rows), const_interval!(2.0, 5.0));
+    /// ```
+    #[must_use]
+    pub fn inf_norm_rows(rows: &[Vec<Interval>]) -> Self {
+        rows.iter()
+            .map(|row| row.iter().fold(Self::zero(), |s, x| s + x.abs()))
+            .fold(Self::zero(), Self::max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn inf_norm_rows() {
+        assert_eq!(I::inf_norm_rows(&[]), const_interval!(0.0));
+        assert_eq!(I::inf_norm_rows(&[vec![]]), const_interval!(0.0));
+
+        let rows = vec![
+            vec![const_interval!(1.0), const_interval!(-2.0)],
+            vec![const_interval!(3.0), const_interval!(4.0)],
+        ];
+        assert_eq!(I::inf_norm_rows(&rows), const_interval!(7.0));
+
+        let rows = vec![
+            vec![const_interval!(-1.0, 1.0), const_interval!(2.0, 3.0)],
+            vec![const_interval!(-4.0, 0.0)],
+        ];
+        assert_eq!(I::inf_norm_rows(&rows), const_interval!(2.0, 4.0));
+
+        let rows = vec![vec![const_interval!(1.0)], vec![I::EMPTY]];
+        assert!(I::inf_norm_rows(&rows).is_empty());
+    }
+}
@@ -0,0 +1,539 @@
+use crate::{
+    arith::{add_down, add_up},
+    interval::*,
+};
+
+// Returns `x - y` rounded toward +∞.
+fn sub_up(x: f64, y: f64) -> f64 {
+    add_up(x, -y)
+}
+
+impl Interval {
+    /// Returns the magnitude of `self` if it is nonempty; otherwise, a NaN.
+    ///
+    /// The magnitude of a nonempty interval $𝒙 = \[a, b\]$ is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{mag}(𝒙) = \sup \set{|x| ∣ x ∈ 𝒙} = \max \set{|a|, |b|}.
+    /// $$
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-2.0, 3.0).mag(), 3.0);
+    /// assert_eq!(const_interval!(f64::NEG_INFINITY, 1.0).mag(), f64::INFINITY);
+    /// assert!(Interval::EMPTY.mag().is_nan());
+    /// ```
+    ///
+    /// See also: [`Interval::mig`].
+    pub fn mag(self) -> f64 {
+        if self.is_empty() {
+            return f64::NAN;
+        }
+
+        f64::max(self.inf.abs(), self.sup.abs())
+    }
+
+    /// Returns the mignitude of `self` if it is nonempty; otherwise, a NaN.
+    ///
+    /// The mignitude of a nonempty interval $𝒙 = \[a, b\]$ is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{mig}(𝒙) = \inf \set{|x| ∣ x ∈ 𝒙} = \begin{cases}
+    ///   \min \set{|a|, |b|} & \text{if } \operatorname{sgn}(a) = \operatorname{sgn}(b), \\\\
+    ///   0                    & \text{otherwise}.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-2.0, 3.0).mig(), 0.0);
+    /// assert_eq!(const_interval!(-5.0, -2.0).mig(), 2.0);
+    /// assert!(Interval::EMPTY.mig().is_nan());
+    /// ```
+    ///
+    /// See also: [`Interval::mag`].
+    pub fn mig(self) -> f64 {
+        if self.is_empty() {
+            return f64::NAN;
+        }
+
+        if self.inf > 0.0 {
+            self.inf
+        } else if self.sup < 0.0 {
+            -self.sup
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the only member of `self` if it is a singleton interval $\[x, x\]$.
+    ///
+    /// Otherwise, that is, if `self` is empty or contains more than one real number,
+    /// returns an error with [`IntervalErrorKind::PossiblyUndefinedOperation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(2.0).try_into_f64(), Ok(2.0));
+    /// assert!(const_interval!(1.0, 2.0).try_into_f64().is_err());
+    /// assert!(Interval::EMPTY.try_into_f64().is_err());
+    /// ```
+    pub fn try_into_f64(self) -> Result<f64> {
+        if self.is_singleton() {
+            Ok(self.inf)
+        } else {
+            Err(IntervalError {
+                kind: IntervalErrorKind::PossiblyUndefinedOperation,
+            })
+        }
+    }
+
+    /// Returns the midpoint of `self`.
+    ///
+    /// The bounds are combined as $a/2 + b/2$, so the computation never overflows,
+    /// and the result is rounded to nearest.
+    ///
+    /// The special cases are:
+    ///
+    /// | Interval                    | Result            |
+    /// | --------------------------- | ----------------- |
+    /// | $∅$                         | NaN               |
+    /// | $\[-∞, +∞\]$                | $0$               |
+    /// | $\[a, +∞\]$, $a$ finite     | [`f64::MAX`]      |
+    /// | $\[-∞, b\]$, $b$ finite     | [`f64::MIN`]      |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-1.0, 2.0).mid(), 0.5);
+    /// assert_eq!(const_interval!(3.0).mid(), 3.0);
+    /// assert_eq!(const_interval!(f64::MAX, f64::MAX).mid(), f64::MAX);
+    /// assert_eq!(const_interval!(1.0, f64::INFINITY).mid(), f64::MAX);
+    /// assert_eq!(const_interval!(f64::NEG_INFINITY, 1.0).mid(), f64::MIN);
+    /// assert_eq!(Interval::ENTIRE.mid(), 0.0);
+    /// assert!(Interval::EMPTY.mid().is_nan());
+    /// ```
+    ///
+    /// See also: [`Interval::mid_toward_zero`].
+    pub fn mid(self) -> f64 {
+        let (a, b) = (self.inf, self.sup);
+        if self.is_empty() {
+            return f64::NAN;
+        }
+
+        match (a == f64::NEG_INFINITY, b == f64::INFINITY) {
+            (true, true) => 0.0,
+            (true, false) => f64::MIN,
+            (false, true) => f64::MAX,
+            // The halving can underflow for subnormal bounds, e.g., to 0 for [5e-324, 5e-324],
+            // so the result is clamped to stay in `self`.
+            _ => (0.5 * a + 0.5 * b).clamp(a, b),
+        }
+    }
+
+    /// Returns the midpoint of `self` rounded toward zero.
+    ///
+    /// This is the same as [`Interval::mid`] except for the rounding:
+    /// if the exact midpoint is not representable, the one of the two adjacent [`f64`] numbers
+    /// that is closer to zero is returned, instead of the nearest one.
+    /// This is useful when a representative point must not be larger in magnitude
+    /// than the exact midpoint, e.g., for conservative seeds of iterations.
+    /// The special cases are handled in the same way as [`Interval::mid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(1.0, 2.0).mid_toward_zero(), 1.5);
+    /// let x = interval!(1.0 + f64::EPSILON, 1.0 + 2.0 * f64::EPSILON).unwrap();
+    /// assert_eq!(x.mid_toward_zero(), 1.0 + f64::EPSILON);
+    /// assert!(Interval::EMPTY.mid_toward_zero().is_nan());
+    /// assert_eq!(Interval::ENTIRE.mid_toward_zero(), 0.0);
+    /// ```
+    pub fn mid_toward_zero(self) -> f64 {
+        let (a, b) = (self.inf, self.sup);
+        if self.is_empty() {
+            return f64::NAN;
+        }
+        match (a == f64::NEG_INFINITY, b == f64::INFINITY) {
+            (true, true) => return 0.0,
+            (true, false) => return f64::MIN,
+            (false, true) => return f64::MAX,
+            _ => (),
+        }
+
+        let (s, e) = crate::arith::two_sum(a, b);
+        let (m, err) = if s.is_finite() {
+            // The halving is exact unless `s` is tiny, in which case `a + b` is exact instead.
+            // Either way, the sign of `err` tells the direction of the exact midpoint from `m`.
+            let m = 0.5 * s;
+            (m, e + (s - 2.0 * m))
+        } else {
+            // Both bounds are large, so halving them is exact.
+            crate::arith::two_sum(0.5 * a, 0.5 * b)
+        };
+
+        if m > 0.0 && err < 0.0 {
+            m.next_down()
+        } else if m < 0.0 && err > 0.0 {
+            m.next_up()
+        } else {
+            m
+        }
+    }
+
+    /// Returns the width of `self`, $b - a$ for $\self = \[a, b\]$, rounded upward.
+    ///
+    /// The result is never smaller than the exact width. It is NaN if `self` is empty,
+    /// and $+∞$ if `self` is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(2.0, 5.0).wid(), 3.0);
+    /// assert_eq!(const_interval!(2.0).wid(), 0.0);
+    /// assert!(Interval::EMPTY.wid().is_nan());
+    /// assert_eq!(Interval::ENTIRE.wid(), f64::INFINITY);
+    /// ```
+    pub fn wid(self) -> f64 {
+        if self.is_empty() {
+            return f64::NAN;
+        }
+
+        sub_up(self.sup, self.inf)
+    }
+
+    /// Returns the radius of `self`, which is the smallest [`f64`] number $r$ such that
+    /// $\self ⊆ \[m - r, m + r\]$, where $m$ is [`self.mid()`](`Interval::mid`).
+    ///
+    /// The distances from $m$ to the bounds are rounded upward, so the result is not
+    /// `(self.sup - self.inf) / 2.0` in general.
+    /// The result is NaN if `self` is empty, and $+∞$ if `self` is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(1.0, 3.0).rad(), 1.0);
+    /// assert_eq!(const_interval!(2.0).rad(), 0.0);
+    /// assert_eq!(Interval::ENTIRE.rad(), f64::INFINITY);
+    /// assert!(Interval::EMPTY.rad().is_nan());
+    /// ```
+    ///
+    /// See also: [`Interval::mid_rad`].
+    pub fn rad(self) -> f64 {
+        self.mid_rad().1
+    }
+
+    /// Returns `(self.mid(), self.rad())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-1.0, 3.0).mid_rad(), (1.0, 2.0));
+    /// ```
+    pub fn mid_rad(self) -> (f64, f64) {
+        let m = self.mid();
+        if self.is_empty() {
+            return (m, f64::NAN);
+        }
+        if !self.is_common_interval() {
+            return (m, f64::INFINITY);
+        }
+
+        (m, f64::max(sub_up(m, self.inf), sub_up(self.sup, m)))
+    }
+
+    /// Returns the tightest interval that contains $\[m - r, m + r\]$,
+    /// where $m$ and $r$ are `mid` and `rad`, respectively.
+    ///
+    /// The bounds are rounded outward, so the result contains every real number
+    /// within the distance `rad` from `mid`. The result is
+    ///
+    /// - $∅$ if `mid` is not finite, or `rad` is negative or NaN,
+    /// - $\[-∞, +∞\]$ if `mid` is finite and `rad` is $+∞$.
+    ///
+    /// This is the inverse of [`Interval::mid_rad`] in the sense that
+    /// the result of `Interval::from_mid_rad(x.mid(), x.rad())` contains `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(Interval::from_mid_rad(1.0, 0.5), const_interval!(0.5, 1.5));
+    /// // The exact value of 1.0 - 0.1 is below 0.9.
+    /// let x = Interval::from_mid_rad(1.0, 0.1);
+    /// assert!(x.inf < 0.9);
+    /// assert_eq!(Interval::from_mid_rad(1.0, -0.5), Interval::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn from_mid_rad(mid: f64, rad: f64) -> Self {
+        if !mid.is_finite() || rad.is_nan() || rad < 0.0 {
+            return Self::EMPTY;
+        }
+        if rad == f64::INFINITY {
+            return Self::ENTIRE;
+        }
+
+        Self::with_infsup_raw(add_down(mid, -rad), add_up(mid, rad))
+    }
+
+    /// Returns the Hausdorff distance between `self` and `rhs`, rounded upward.
+    ///
+    /// The Hausdorff distance between nonempty intervals $\[a, b\]$ and $\[c, d\]$ is
+    /// $\max \set{|a - c|, |b - d|}$, where the distance between the same infinite bounds is zero.
+    /// It is zero if and only if the intervals are equal.
+    ///
+    /// Following the convention for the Hausdorff distance, the result is zero if both intervals
+    /// are empty, and $+∞$ if exactly one of them is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// // Touching.
+    /// assert_eq!(const_interval!(0.0, 1.0).dist(const_interval!(1.0, 3.0)), 2.0);
+    /// // Overlapping.
+    /// assert_eq!(const_interval!(0.0, 2.0).dist(const_interval!(1.0, 2.5)), 1.0);
+    /// // Disjoint.
+    /// assert_eq!(const_interval!(0.0, 1.0).dist(const_interval!(5.0, 7.0)), 6.0);
+    /// assert_eq!(const_interval!(1.0, f64::INFINITY).dist(const_interval!(2.0, f64::INFINITY)), 1.0);
+    /// assert_eq!(Interval::EMPTY.dist(Interval::EMPTY), 0.0);
+    /// assert_eq!(Interval::EMPTY.dist(const_interval!(1.0)), f64::INFINITY);
+    /// ```
+    pub fn dist(self, rhs: Self) -> f64 {
+        // |x - y| rounded upward.
+        fn abs_sub_up(x: f64, y: f64) -> f64 {
+            if x == y {
+                0.0
+            } else if x > y {
+                sub_up(x, y)
+            } else {
+                sub_up(y, x)
+            }
+        }
+
+        match (self.is_empty(), rhs.is_empty()) {
+            (true, true) => 0.0,
+            (true, false) | (false, true) => f64::INFINITY,
+            _ => f64::max(abs_sub_up(self.inf, rhs.inf), abs_sub_up(self.sup, rhs.sup)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn mag_mig() {
+        assert_eq!(const_interval!(-2.0, 3.0).mag(), 3.0);
+        assert_eq!(const_interval!(-2.0, 3.0).mig(), 0.0);
+        assert_eq!(const_interval!(1.0, 3.0).mag(), 3.0);
+        assert_eq!(const_interval!(1.0, 3.0).mig(), 1.0);
+        assert_eq!(const_interval!(-3.0, -1.0).mag(), 3.0);
+        assert_eq!(const_interval!(-3.0, -1.0).mig(), 1.0);
+        assert_eq!(const_interval!(0.0, 0.0).mag(), 0.0);
+        assert_eq!(const_interval!(0.0, 0.0).mig(), 0.0);
+        assert_eq!(I::ENTIRE.mag(), f64::INFINITY);
+        assert_eq!(I::ENTIRE.mig(), 0.0);
+        assert_eq!(const_interval!(1.0, f64::INFINITY).mig(), 1.0);
+        assert!(I::EMPTY.mag().is_nan());
+        assert!(I::EMPTY.mig().is_nan());
+    }
+
+    #[test]
+    fn try_into_f64() {
+        assert_eq!(const_interval!(-1.5).try_into_f64(), Ok(-1.5));
+        assert_eq!(const_interval!(f64::MAX).try_into_f64(), Ok(f64::MAX));
+        let x = const_interval!(-0.0, 0.0).try_into_f64().unwrap();
+        assert_eq!(x, 0.0);
+
+        for x in [
+            const_interval!(1.0, 2.0),
+            const_interval!(1.0, 1.0 + f64::EPSILON),
+            const_interval!(0.0, f64::INFINITY),
+            I::ENTIRE,
+            I::EMPTY,
+        ] {
+            assert_eq!(x.try_into_f64().unwrap_err().kind(), IntervalErrorKind::PossiblyUndefinedOperation);
+        }
+    }
+
+    #[test]
+    fn mid() {
+        assert_eq!(const_interval!(-1.0, 1.0).mid(), 0.0);
+        assert_eq!(const_interval!(2.0, 5.0).mid(), 3.5);
+        assert_eq!(const_interval!(f64::MIN, f64::MAX).mid(), 0.0);
+        assert_eq!(const_interval!(f64::MAX, f64::MAX).mid(), f64::MAX);
+        assert_eq!(const_interval!(f64::MIN, f64::MIN).mid(), f64::MIN);
+
+        // Ties are rounded to even, unlike `mid_toward_zero`.
+        let x = const_interval!(1.0 + f64::EPSILON, 1.0 + 2.0 * f64::EPSILON);
+        assert_eq!(x.mid(), 1.0 + 2.0 * f64::EPSILON);
+        assert!(x.contains(x.mid()));
+
+        // Subnormal bounds.
+        assert_eq!(const_interval!(5e-324, 5e-324).mid(), 5e-324);
+        assert_eq!(const_interval!(-5e-324, -5e-324).mid(), -5e-324);
+        assert_eq!(const_interval!(5e-324, 1e-323).mid(), 5e-324);
+        assert_eq!(const_interval!(-5e-324, 5e-324).mid(), 0.0);
+
+        for x in [I::EMPTY, I::ENTIRE, const_interval!(1.0, f64::INFINITY), const_interval!(f64::NEG_INFINITY, 1.0)] {
+            assert_eq!(x.mid().to_bits(), x.mid_toward_zero().to_bits());
+        }
+    }
+
+    #[test]
+    fn wid() {
+        assert_eq!(const_interval!(2.0, 5.0).wid(), 3.0);
+        assert_eq!(const_interval!(-1.0, 1.0).wid(), 2.0);
+        assert_eq!(const_interval!(-0.0, 0.0).wid(), 0.0);
+
+        // 0.9 - 0.2 is rounded downward under round-to-nearest.
+        let x = const_interval!(0.2, 0.9);
+        assert!(x.wid() > x.sup - x.inf);
+        assert_eq!(x.wid(), (x.sup - x.inf).next_up());
+
+        // 1 + 2^-60 is not representable.
+        let x = interval!(-1.0, 2.0_f64.powi(-60)).unwrap();
+        assert_eq!(x.wid(), 1.0_f64.next_up());
+
+        assert_eq!(const_interval!(f64::MIN, f64::MAX).wid(), f64::INFINITY);
+        assert_eq!(const_interval!(0.0, f64::INFINITY).wid(), f64::INFINITY);
+        assert_eq!(I::ENTIRE.wid(), f64::INFINITY);
+        assert!(I::EMPTY.wid().is_nan());
+    }
+
+    #[test]
+    fn rad() {
+        assert_eq!(const_interval!(1.0, 3.0).rad(), 1.0);
+        assert_eq!(const_interval!(-1.0, 3.0).mid_rad(), (1.0, 2.0));
+        assert_eq!(const_interval!(5.0).rad(), 0.0);
+        assert_eq!(const_interval!(f64::MIN, f64::MAX).mid_rad(), (0.0, f64::MAX));
+        assert_eq!(const_interval!(f64::MAX).mid_rad(), (f64::MAX, 0.0));
+
+        // The enclosure [m - r, m + r] ⊇ x must hold in exact arithmetic,
+        // i.e., m - r ≤ a and b ≤ m + r, which is checked with next_down/next_up
+        // as m ± r is computed in round-to-nearest here.
+        let xs = [
+            const_interval!(0.1, 0.7),
+            const_interval!(1.0, 1.0 + 3.0 * f64::EPSILON),
+            const_interval!(-1e-300, 1e300),
+            const_interval!(1.0 / 3.0, 2.0 / 3.0),
+            const_interval!(-7.3, 1e-8),
+        ];
+        for x in xs {
+            let (m, r) = x.mid_rad();
+            assert!(x.contains(m));
+            assert!((m - r).next_down() <= x.inf && x.sup <= (m + r).next_up());
+            assert!(r >= (x.sup - x.inf) / 2.0);
+        }
+
+        // 1 + 3ε: m = 1 + 2ε (ties to even), so r must be 2ε rather than 1.5ε (= 3ε / 2).
+        let x = const_interval!(1.0, 1.0 + 3.0 * f64::EPSILON);
+        assert_eq!(x.mid_rad(), (1.0 + 2.0 * f64::EPSILON, 2.0 * f64::EPSILON));
+
+        assert!(I::EMPTY.rad().is_nan());
+        assert!(I::EMPTY.mid_rad().0.is_nan());
+        assert_eq!(I::ENTIRE.mid_rad(), (0.0, f64::INFINITY));
+        assert_eq!(const_interval!(1.0, f64::INFINITY).rad(), f64::INFINITY);
+        assert_eq!(const_interval!(f64::NEG_INFINITY, 1.0).rad(), f64::INFINITY);
+    }
+
+    #[test]
+    fn from_mid_rad() {
+        assert_eq!(I::from_mid_rad(1.0, 0.5), const_interval!(0.5, 1.5));
+        assert_eq!(I::from_mid_rad(-2.0, 0.0), const_interval!(-2.0));
+
+        // Inexact bounds are rounded outward.
+        // 1 ∓ 0.1 are rounded to nearest to 0.9 and 1.1, which are above the exact values.
+        assert_eq!(I::from_mid_rad(1.0, 0.1), interval!(0.9f64.next_down(), 1.1).unwrap());
+        // 0.1 - 0.2 is exact.
+        assert_eq!(I::from_mid_rad(0.1, 0.2), interval!(-0.1, 0.30000000000000004).unwrap());
+
+        // Round trip through `mid_rad`.
+        for x in [const_interval!(0.1, 0.7), const_interval!(-1e300, 1e-300), const_interval!(3.0)] {
+            let (m, r) = x.mid_rad();
+            assert!(x.subset(I::from_mid_rad(m, r)));
+        }
+
+        // Overflow.
+        assert_eq!(I::from_mid_rad(f64::MAX, f64::MAX), const_interval!(0.0, f64::INFINITY));
+
+        assert_eq!(I::from_mid_rad(1.0, f64::INFINITY), I::ENTIRE);
+        assert_eq!(I::from_mid_rad(1.0, -0.5), I::EMPTY);
+        assert_eq!(I::from_mid_rad(1.0, f64::NAN), I::EMPTY);
+        assert_eq!(I::from_mid_rad(f64::INFINITY, 1.0), I::EMPTY);
+        assert_eq!(I::from_mid_rad(f64::NAN, 1.0), I::EMPTY);
+    }
+
+    #[test]
+    fn dist() {
+        const INF: f64 = f64::INFINITY;
+        let xs = [
+            const_interval!(0.0, 1.0),
+            const_interval!(-0.0, 0.0),
+            const_interval!(0.1, 0.3),
+            const_interval!(f64::NEG_INFINITY, 1.0),
+            const_interval!(1.0, INF),
+            I::ENTIRE,
+            I::EMPTY,
+        ];
+        for x in xs {
+            for y in xs {
+                assert_eq!(x.dist(y), y.dist(x));
+                assert_eq!(x.dist(y) == 0.0, x == y, "{}.dist({})", x, y);
+            }
+        }
+
+        // The distance is rounded upward.
+        let d = const_interval!(1.0).dist(const_interval!(-f64::EPSILON / 4.0));
+        assert_eq!(d, 1.0 + f64::EPSILON);
+
+        assert_eq!(const_interval!(0.0, 1.0).dist(const_interval!(-0.0, 1.0)), 0.0);
+        assert_eq!(const_interval!(0.0, 1.0).dist(const_interval!(0.5, 0.75)), 0.5);
+        assert_eq!(const_interval!(f64::MIN, f64::MAX).dist(const_interval!(f64::MAX)), INF);
+        assert_eq!(const_interval!(0.0, 1.0).dist(const_interval!(0.0, INF)), INF);
+        assert_eq!(I::ENTIRE.dist(const_interval!(0.0, INF)), INF);
+        assert_eq!(I::ENTIRE.dist(I::EMPTY), INF);
+    }
+
+    #[test]
+    fn mid_toward_zero() {
+        const EPS: f64 = f64::EPSILON;
+
+        assert_eq!(const_interval!(-1.0, 1.0).mid_toward_zero(), 0.0);
+        assert_eq!(const_interval!(2.0, 5.0).mid_toward_zero(), 3.5);
+        assert_eq!(const_interval!(f64::MAX, f64::MAX).mid_toward_zero(), f64::MAX);
+        assert_eq!(const_interval!(f64::MIN, f64::MAX).mid_toward_zero(), 0.0);
+
+        // Subnormal bounds: 5e-324 is the smallest positive number.
+        assert_eq!(const_interval!(5e-324, 5e-324).mid_toward_zero(), 5e-324);
+        assert_eq!(const_interval!(1e-323, 1.5e-323).mid_toward_zero(), 1e-323);
+        assert_eq!(const_interval!(-1.5e-323, -1e-323).mid_toward_zero(), -1e-323);
+        assert_eq!(const_interval!(-5e-324, 1e-323).mid_toward_zero(), 0.0);
+        assert_eq!(const_interval!(-5e-324, 3.0).mid_toward_zero(), 1.5f64.next_down());
+
+        // The exact midpoints 1 + 1.5ε and -(1 + 1.5ε) are ties, which are rounded to even
+        // under round-to-nearest.
+        let x = const_interval!(1.0 + EPS, 1.0 + 2.0 * EPS);
+        assert_eq!(0.5 * x.inf + 0.5 * x.sup, 1.0 + 2.0 * EPS);
+        assert_eq!(x.mid_toward_zero(), 1.0 + EPS);
+        assert_eq!((-x).mid_toward_zero(), -1.0 - EPS);
+
+        assert!(I::EMPTY.mid_toward_zero().is_nan());
+        assert_eq!(I::ENTIRE.mid_toward_zero(), 0.0);
+        assert_eq!(const_interval!(1.0, f64::INFINITY).mid_toward_zero(), f64::MAX);
+        assert_eq!(const_interval!(f64::NEG_INFINITY, 1.0).mid_toward_zero(), f64::MIN);
+    }
+}
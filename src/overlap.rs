@@ -0,0 +1,150 @@
+use crate::interval::*;
+use std::cmp::Ordering;
+
+/// The overlapping state between intervals, returned by [`Interval::overlap`].
+///
+/// The nonempty cases correspond to the thirteen relations of Allen's interval algebra.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OverlappingState {
+    /// Both $\self$ and $\rhs$ are empty.
+    BothEmpty,
+    /// $\self$ is empty while $\rhs$ is not.
+    FirstEmpty,
+    /// $\rhs$ is empty while $\self$ is not.
+    SecondEmpty,
+    /// $b < c$.
+    Before,
+    /// $a < b = c < d$.
+    Meets,
+    /// $a < c < b < d$.
+    Overlaps,
+    /// $a = c ∧ b < d$.
+    Starts,
+    /// $c < a ∧ b < d$.
+    ContainedBy,
+    /// $c < a ∧ b = d$.
+    Finishes,
+    /// $a = c ∧ b = d$.
+    Equals,
+    /// $a < c ∧ b = d$.
+    FinishedBy,
+    /// $a < c ∧ d < b$.
+    Contains,
+    /// $a = c ∧ d < b$.
+    StartedBy,
+    /// $c < a < d < b$.
+    OverlappedBy,
+    /// $c < a = d < b$.
+    MetBy,
+    /// $d < a$.
+    After,
+}
+
+impl Interval {
+    /// Returns the overlapping state between `self` $= \[a, b\]$ and `rhs` $= \[c, d\]$.
+    ///
+    /// Exactly one of the states listed in [`OverlappingState`] holds for any pair of intervals.
+    /// For nonempty intervals, the state is determined as follows:
+    ///
+    /// |         | $b < d$                  | $b = d$        | $b > d$                   |
+    /// | :-----: | :----------------------: | :------------: | :-----------------------: |
+    /// | $a < c$ | `Before`, `Meets` or `Overlaps` | `FinishedBy` | `Contains`          |
+    /// | $a = c$ | `Starts`                 | `Equals`       | `StartedBy`               |
+    /// | $a > c$ | `ContainedBy`            | `Finishes`     | `OverlappedBy`, `MetBy` or `After` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::{OverlappingState as O, *};
+    /// let x = const_interval!(1.0, 2.0);
+    ///
+    /// assert_eq!(Interval::EMPTY.overlap(Interval::EMPTY), O::BothEmpty);
+    /// assert_eq!(Interval::EMPTY.overlap(x), O::FirstEmpty);
+    /// assert_eq!(x.overlap(Interval::EMPTY), O::SecondEmpty);
+    ///
+    /// assert_eq!(x.overlap(const_interval!(3.0, 4.0)), O::Before);
+    /// assert_eq!(x.overlap(const_interval!(2.0, 3.0)), O::Meets);
+    /// assert_eq!(x.overlap(const_interval!(1.5, 3.0)), O::Overlaps);
+    /// assert_eq!(x.overlap(const_interval!(1.0, 3.0)), O::Starts);
+    /// assert_eq!(x.overlap(const_interval!(0.0, 3.0)), O::ContainedBy);
+    /// assert_eq!(x.overlap(const_interval!(0.0, 2.0)), O::Finishes);
+    /// assert_eq!(x.overlap(const_interval!(1.0, 2.0)), O::Equals);
+    /// assert_eq!(x.overlap(const_interval!(1.5, 2.0)), O::FinishedBy);
+    /// assert_eq!(x.overlap(const_interval!(1.25, 1.75)), O::Contains);
+    /// assert_eq!(x.overlap(const_interval!(1.0, 1.5)), O::StartedBy);
+    /// assert_eq!(x.overlap(const_interval!(0.0, 1.5)), O::OverlappedBy);
+    /// assert_eq!(x.overlap(const_interval!(0.0, 1.0)), O::MetBy);
+    /// assert_eq!(x.overlap(const_interval!(-1.0, 0.0)), O::After);
+    /// ```
+    pub fn overlap(self, rhs: Self) -> OverlappingState {
+        use OverlappingState::*;
+
+        if self.either_empty(rhs) {
+            return match (self.is_empty(), rhs.is_empty()) {
+                (true, true) => BothEmpty,
+                (true, false) => FirstEmpty,
+                _ => SecondEmpty,
+            };
+        }
+
+        let [a, b, c, d] = [self.inf, self.sup, rhs.inf, rhs.sup];
+        if b < c {
+            return Before;
+        }
+        if d < a {
+            return After;
+        }
+
+        // Now c ≤ b ∧ a ≤ d. Neither bound is NaN, thus `partial_cmp` always succeeds.
+        let cmp = |x: f64, y: f64| x.partial_cmp(&y).unwrap();
+        match (cmp(a, c), cmp(b, d)) {
+            (Ordering::Less, Ordering::Less) if b == c => Meets,
+            (Ordering::Less, Ordering::Less) => Overlaps,
+            (Ordering::Less, Ordering::Equal) => FinishedBy,
+            (Ordering::Less, Ordering::Greater) => Contains,
+            (Ordering::Equal, Ordering::Less) => Starts,
+            (Ordering::Equal, Ordering::Equal) => Equals,
+            (Ordering::Equal, Ordering::Greater) => StartedBy,
+            (Ordering::Greater, Ordering::Less) => ContainedBy,
+            (Ordering::Greater, Ordering::Equal) => Finishes,
+            (Ordering::Greater, Ordering::Greater) if a == d => MetBy,
+            (Ordering::Greater, Ordering::Greater) => OverlappedBy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+    use OverlappingState::*;
+
+    #[test]
+    fn overlap() {
+        // Singletons.
+        let x = const_interval!(1.0, 1.0);
+        assert_eq!(x.overlap(x), Equals);
+        assert_eq!(x.overlap(const_interval!(1.0, 2.0)), Starts);
+        assert_eq!(x.overlap(const_interval!(0.0, 1.0)), Finishes);
+        assert_eq!(x.overlap(const_interval!(0.0, 2.0)), ContainedBy);
+        assert_eq!(x.overlap(const_interval!(2.0, 2.0)), Before);
+        assert_eq!(const_interval!(1.0, 2.0).overlap(x), StartedBy);
+        assert_eq!(const_interval!(0.0, 1.0).overlap(x), FinishedBy);
+
+        // Signed zeros.
+        assert_eq!(const_interval!(-0.0, 1.0).overlap(const_interval!(0.0, 1.0)), Equals);
+        assert_eq!(const_interval!(-1.0, -0.0).overlap(const_interval!(0.0, 1.0)), Meets);
+
+        // Unbounded intervals.
+        assert_eq!(I::ENTIRE.overlap(I::ENTIRE), Equals);
+        assert_eq!(I::ENTIRE.overlap(const_interval!(1.0, 2.0)), Contains);
+        assert_eq!(const_interval!(1.0, 2.0).overlap(I::ENTIRE), ContainedBy);
+        let neg = const_interval!(f64::NEG_INFINITY, 0.0);
+        let pos = const_interval!(0.0, f64::INFINITY);
+        assert_eq!(neg.overlap(pos), Meets);
+        assert_eq!(pos.overlap(neg), MetBy);
+        assert_eq!(neg.overlap(I::ENTIRE), Starts);
+        assert_eq!(pos.overlap(I::ENTIRE), Finishes);
+        assert_eq!(I::EMPTY.overlap(I::ENTIRE), FirstEmpty);
+    }
+}
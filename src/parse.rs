@@ -0,0 +1,359 @@
+use crate::interval::*;
+use std::{cmp::Ordering, convert::TryFrom, num::IntErrorKind, str::FromStr};
+
+// Replaces the Unicode symbols that are commonly produced by prettifying UIs
+// with their ASCII counterparts understood by `f64::from_str`.
+fn normalize(s: &str) -> String {
+    s.replace('\u{2212}', "-").replace('\u{221e}', "inf")
+}
+
+// A decimal number `sign × 0.d₁d₂… × 10^(exp + 1)`, where `digits` has neither leading
+// nor trailing zeros. Zero is represented by empty `digits`.
+struct Decimal {
+    neg: bool,
+    digits: String,
+    exp: i64,
+}
+
+impl Decimal {
+    const MAX_EXP: i64 = 1 << 40;
+
+    // Parses a decimal literal such as `-12.5e-3`. Returns `None` for anything else,
+    // including `inf` and `nan`.
+    fn parse(s: &str) -> Option<Self> {
+        let (neg, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (mantissa, e) = match s.split_once(['e', 'E']) {
+            // Exponents far beyond the range of f64 are saturated, which does not change
+            // the rounding of the number and keeps `exp` below from overflowing.
+            Some((m, e)) => match e.parse::<i64>() {
+                Ok(e) => (m, e.clamp(-Self::MAX_EXP, Self::MAX_EXP)),
+                Err(err) => match err.kind() {
+                    IntErrorKind::PosOverflow => (m, Self::MAX_EXP),
+                    IntErrorKind::NegOverflow => (m, -Self::MAX_EXP),
+                    _ => return None,
+                },
+            },
+            None => (s, 0),
+        };
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if int.is_empty() && frac.is_empty() || !(int.chars().chain(frac.chars())).all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let all = format!("{}{}", int, frac);
+        let leading_zeros = all.len() - all.trim_start_matches('0').len();
+        let digits = all.trim_matches('0').to_string();
+        let exp = int.len() as i64 - 1 - leading_zeros as i64 + e;
+        Some(Self { neg, digits, exp })
+    }
+
+    // Returns the exact decimal value of `x`, which is finite.
+    fn from_f64(x: f64) -> Self {
+        // Every f64 number has at most 767 significant decimal digits.
+        Self::parse(&format!("{:.767e}", x)).unwrap()
+    }
+
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        let sign = |x: &Self| match (x.digits.is_empty(), x.neg) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        };
+        let (s, t) = (sign(self), sign(rhs));
+        if s != t || s == 0 {
+            return s.cmp(&t);
+        }
+
+        let abs_cmp = self.exp.cmp(&rhs.exp).then_with(|| self.digits.cmp(&rhs.digits));
+        if s > 0 {
+            abs_cmp
+        } else {
+            abs_cmp.reverse()
+        }
+    }
+}
+
+// Formats `x` with `precision` digits after the decimal point, rounded toward -∞
+// (if `up` is `false`) or +∞ (if `up` is `true`), so that the decimal number encloses `x`.
+// `format!` rounds to nearest, so the last digit is stepped outward if it went inward.
+pub(crate) fn format_bound(x: f64, precision: usize, up: bool) -> String {
+    let s = format!("{:.*}", precision, x);
+    if !x.is_finite() {
+        return s;
+    }
+    match (Decimal::parse(&s).unwrap().cmp(&Decimal::from_f64(x)), up) {
+        (Ordering::Greater, false) | (Ordering::Less, true) => (),
+        _ => return s,
+    }
+
+    let (neg, mag) = match s.strip_prefix('-') {
+        Some(mag) => (true, mag),
+        None => (false, s.as_str()),
+    };
+    let mut digits = mag.bytes().filter(|&c| c != b'.').map(|c| c - b'0').collect::<Vec<_>>();
+    // Stepping toward -∞ increases the magnitude of a negative number, and vice versa.
+    // The magnitude to be decreased is never zero, as it is greater than |x|.
+    if neg != up {
+        match digits.iter().rposition(|&d| d != 9) {
+            Some(i) => {
+                digits[i] += 1;
+                digits[i + 1..].fill(0);
+            }
+            None => {
+                digits.fill(0);
+                digits.insert(0, 1);
+            }
+        }
+    } else {
+        let i = digits.iter().rposition(|&d| d != 0).unwrap();
+        digits[i] -= 1;
+        digits[i + 1..].fill(9);
+    }
+
+    let (int, frac) = digits.split_at(digits.len() - precision);
+    let int = &int[int.iter().position(|&d| d != 0).unwrap_or(int.len() - 1)..];
+    let digit_str = |ds: &[u8]| ds.iter().map(|&d| char::from(b'0' + d)).collect::<String>();
+    let mut t = format!("{}{}", if neg { "-" } else { "" }, digit_str(int));
+    if precision > 0 {
+        t.push('.');
+        t.push_str(&digit_str(frac));
+    }
+    t
+}
+
+// Parses a bound and rounds it toward -∞ (if `up` is `false`) or +∞ (if `up` is `true`),
+// so that the result encloses the decimal number written in `s`.
+fn parse_bound(s: &str, up: bool) -> Result<f64> {
+    let s = s.trim();
+    let x = match s.parse::<f64>() {
+        Ok(x) if !x.is_nan() => x,
+        _ => {
+            return Err(IntervalError {
+                kind: IntervalErrorKind::UndefinedOperation,
+            })
+        }
+    };
+
+    let d = match Decimal::parse(s) {
+        Some(d) => d,
+        // `inf` and the like are exact.
+        None => return Ok(x),
+    };
+    if x.is_infinite() {
+        // A finite number that overflows.
+        return Ok(match (x > 0.0, up) {
+            (true, false) => f64::MAX,
+            (false, true) => f64::MIN,
+            _ => x,
+        });
+    }
+
+    Ok(match (Decimal::from_f64(x).cmp(&d), up) {
+        (Ordering::Greater, false) => x.next_down(),
+        (Ordering::Less, true) => x.next_up(),
+        _ => x,
+    })
+}
+
+impl FromStr for Interval {
+    type Err = IntervalError;
+
+    /// Parses an interval from a string of the form `[a, b]`, `[empty]`, `[entire]`
+    /// or a bare number `a`, which denotes the singleton `[a, a]`.
+    ///
+    /// The bounds can be written either in ASCII (`-inf`, `inf`) or
+    /// with the Unicode minus sign (U+2212) and the infinity symbol (U+221E),
+    /// such as `[−∞, ∞]`.
+    ///
+    /// The bounds are rounded outward, so the result is the tightest interval that contains
+    /// the decimal numbers as written. For example, `[0.1, 0.1]` is not a singleton,
+    /// since $0.1$ is not representable as an [`f64`] number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!("[1, 2]".parse::<Interval>(), Ok(const_interval!(1.0, 2.0)));
+    /// assert_eq!("[−∞, ∞]".parse::<Interval>(), Ok(Interval::ENTIRE));
+    /// assert_eq!("[empty]".parse::<Interval>(), Ok(Interval::EMPTY));
+    /// let x = "0.1".parse::<Interval>().unwrap();
+    /// assert_eq!(x, interval!(0.1_f64.next_down(), 0.1).unwrap());
+    /// assert!("[2, 1]".parse::<Interval>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let s = normalize(s);
+        let s = s.trim();
+
+        let (a, b) = match s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(s) => match s.split_once(',') {
+                Some((a, b)) => (parse_bound(a, false)?, parse_bound(b, true)?),
+                None => match s.trim() {
+                    "empty" => return Ok(Self::EMPTY),
+                    "entire" => return Ok(Self::ENTIRE),
+                    _ => {
+                        return Err(IntervalError {
+                            kind: IntervalErrorKind::UndefinedOperation,
+                        })
+                    }
+                },
+            },
+            None => (parse_bound(s, false)?, parse_bound(s, true)?),
+        };
+
+        Self::try_from((a, b))
+    }
+}
+
+impl TryFrom<&str> for Interval {
+    type Error = IntervalError;
+
+    /// Parses an interval from a string. Equivalent to [`str::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// use std::convert::TryFrom;
+    /// assert_eq!(Interval::try_from("[1, 2]"), Ok(const_interval!(1.0, 2.0)));
+    /// ```
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn parse() {
+        assert_eq!("[1, 2]".parse::<I>(), Ok(const_interval!(1.0, 2.0)));
+        assert_eq!(" [ -1.5 ,2.5 ] ".parse::<I>(), Ok(const_interval!(-1.5, 2.5)));
+        assert_eq!("3.5".parse::<I>(), Ok(const_interval!(3.5)));
+
+        assert!("".parse::<I>().is_err());
+        assert!("[]".parse::<I>().is_err());
+        assert!("[1]".parse::<I>().is_err());
+        assert!("[1, 2".parse::<I>().is_err());
+        assert!("[2, 1]".parse::<I>().is_err());
+        assert!("[nan, 1]".parse::<I>().is_err());
+        assert!("[inf, inf]".parse::<I>().is_err());
+    }
+
+    #[test]
+    fn parse_empty_entire() {
+        assert_eq!("[empty]".parse::<I>(), Ok(I::EMPTY));
+        assert_eq!(" [ empty ] ".parse::<I>(), Ok(I::EMPTY));
+        assert_eq!("[entire]".parse::<I>(), Ok(I::ENTIRE));
+        assert_eq!("[ entire]".parse::<I>(), Ok(I::ENTIRE));
+        assert!("empty".parse::<I>().is_err());
+        assert!("[Empty]".parse::<I>().is_err());
+        assert!("[empty, 1]".parse::<I>().is_err());
+        assert_eq!(
+            "[whole]".parse::<I>().unwrap_err().kind(),
+            IntervalErrorKind::UndefinedOperation
+        );
+    }
+
+    #[test]
+    fn parse_outward() {
+        // 0.1 is below its nearest f64 number, and 0.3 is above.
+        assert_eq!("[0.1, 0.1]".parse::<I>(), interval!(0.1_f64.next_down(), 0.1));
+        assert_eq!("0.3".parse::<I>(), interval!(0.3, 0.3_f64.next_up()));
+        assert_eq!("[-0.3, 0.1]".parse::<I>(), interval!(-0.3_f64.next_up(), 0.1));
+        assert_eq!("[0.1, 0.3]".parse::<I>(), interval!(0.1_f64.next_down(), 0.3_f64.next_up()));
+
+        // Exact decimals are not widened.
+        assert_eq!("[0.5, 1.25]".parse::<I>(), Ok(const_interval!(0.5, 1.25)));
+        assert_eq!("[-1e3, 2.5e-1]".parse::<I>(), Ok(const_interval!(-1000.0, 0.25)));
+        assert_eq!("[-0.00, 000.0]".parse::<I>(), Ok(const_interval!(0.0)));
+        assert_eq!("9007199254740993".parse::<I>(), interval!(9007199254740992.0, 9007199254740994.0));
+        let x = "0.1000000000000000055511151231257827021181583404541015625".parse::<I>();
+        assert_eq!(x, interval!(0.1));
+
+        // Overflow and underflow.
+        assert_eq!("[1e400, inf]".parse::<I>(), interval!(f64::MAX, f64::INFINITY));
+        assert_eq!("[-inf, -1e400]".parse::<I>(), interval!(f64::NEG_INFINITY, f64::MIN));
+        assert_eq!("1e-400".parse::<I>(), interval!(0.0, f64::from_bits(1)));
+        assert_eq!("-1e-400".parse::<I>(), interval!(-f64::from_bits(1), 0.0));
+
+        // Exponents that do not fit in i64.
+        let x = "[0, 1e-99999999999999999999]".parse::<I>();
+        assert_eq!(x, interval!(0.0, f64::from_bits(1)));
+        let x = "[1e99999999999999999999, inf]".parse::<I>();
+        assert_eq!(x, interval!(f64::MAX, f64::INFINITY));
+        let x = "[-1e99999999999999999999, -1e-99999999999999999999]".parse::<I>();
+        assert_eq!(x, interval!(f64::NEG_INFINITY, -0.0));
+        assert!("1e99999999999999999999x".parse::<I>().is_err());
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let xs = [
+            const_interval!(1.0, 2.0),
+            const_interval!(-1.5, 0.25),
+            const_interval!(f64::NEG_INFINITY, 3.0),
+            const_interval!(0.0, f64::INFINITY),
+            const_interval!(0.1, 0.3),
+            const_interval!(1e-300, 1e300),
+            interval!(1.0 / 3.0, 2.0 / 3.0).unwrap(),
+            I::ENTIRE,
+            I::EMPTY,
+        ];
+        for x in xs {
+            let y = x.to_string().parse::<I>().unwrap();
+            // Display prints the shortest decimal that rounds to each bound,
+            // which is not the exact value in general, so the result can be wider.
+            assert!(x.subset(y), "{} is not a subset of {}", x, y);
+            if !x.is_empty() {
+                assert!(y.inf >= x.inf.next_down() && y.sup <= x.sup.next_up(), "{} is too wide", y);
+            }
+        }
+
+        for x in [const_interval!(1.0, 2.0), const_interval!(-1.5, 0.25), I::ENTIRE, I::EMPTY] {
+            assert_eq!(x.to_string().parse::<I>(), Ok(x));
+        }
+
+        // With a precision, the bounds are rounded outward.
+        for x in xs {
+            for p in 0..5 {
+                let y = format!("{:.*}", p, x).parse::<I>().unwrap();
+                assert!(x.subset(y), "{} is not a subset of {} ({:.*})", x, y, p, x);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_unicode() {
+        let cases = [
+            ("[-inf, inf]", "[−∞, ∞]"),
+            ("[-inf, inf]", "[−∞, +∞]"),
+            ("[-inf, 1]", "[−∞, 1]"),
+            ("[-2, inf]", "[−2, ∞]"),
+            ("[-2, -1]", "[−2, −1]"),
+            ("-0.5", "−0.5"),
+        ];
+        for (ascii, unicode) in cases {
+            let x = ascii.parse::<I>();
+            assert!(x.is_ok());
+            assert_eq!(x, unicode.parse::<I>());
+        }
+
+        assert_eq!("[−∞, ∞]".parse::<I>(), Ok(I::ENTIRE));
+        assert_eq!(
+            "[−∞, −1]".parse::<I>(),
+            Ok(const_interval!(f64::NEG_INFINITY, -1.0))
+        );
+    }
+
+    #[test]
+    fn try_from_str() {
+        for s in ["[1, 2]", "3.5", "[−∞, ∞]", "[2, 1]", ""] {
+            assert_eq!(I::try_from(s), s.parse::<I>());
+        }
+    }
+}
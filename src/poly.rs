@@ -0,0 +1,295 @@
+use crate::interval::*;
+
+// Evaluates the polynomial with the coefficients `coeffs` at `x` by Horner's scheme,
+// where `coeffs[0]` is the coefficient of the highest-degree term.
+// Returns [0, 0] for an empty slice.
+pub(crate) fn horner(x: Interval, coeffs: &[Interval]) -> Interval {
+    match coeffs.split_first() {
+        Some((&c0, rest)) => rest.iter().fold(c0, |y, &c| y * x + c),
+        None => Interval::zero(),
+    }
+}
+
+impl Interval {
+    /// Returns an enclosure of the range of the rational function $P(x) / Q(x)$ over `x`,
+    /// where $P$ and $Q$ are the polynomials with the coefficients `num` and `den`, respectively.
+    ///
+    /// The coefficients are ordered from the highest-degree term to the constant term,
+    /// and an empty slice denotes the zero polynomial.
+    /// Both polynomials are evaluated by Horner's scheme, then divided.
+    /// If the enclosure of $Q(x)$ contains zero, which happens near a pole,
+    /// the result is a half-unbounded interval or [`Interval::ENTIRE`],
+    /// following the definition of the division.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// // 1 / (x - 1)
+    /// let num = [const_interval!(1.0)];
+    /// let den = [const_interval!(1.0), const_interval!(-1.0)];
+    /// let y = Interval::eval_rational(const_interval!(2.0, 3.0), &num, &den);
+    /// assert_eq!(y, const_interval!(0.5, 1.0));
+    /// let y = Interval::eval_rational(const_interval!(0.5, 1.5), &num, &den);
+    /// assert_eq!(y, Interval::ENTIRE);
+    /// ```
+    #[must_use]
+    pub fn eval_rational(x: Interval, num: &[Interval], den: &[Interval]) -> Self {
+        if x.is_empty() {
+            return x;
+        }
+
+        horner(x, num) / horner(x, den)
+    }
+
+    /// Returns an enclosure of the range of the polynomial with the [`f64`] coefficients `coeffs`
+    /// over `x`, evaluated by Horner's scheme with [`Interval::mul_add`].
+    ///
+    /// The coefficients are ordered from the highest-degree term to the constant term,
+    /// and an empty slice denotes the zero polynomial.
+    /// A coefficient that is infinite or NaN makes the result empty, as in `x + f64::NAN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// // x^2 - 2x + 1
+    /// let y = Interval::eval_poly(&[1.0, -2.0, 1.0], const_interval!(3.0));
+    /// assert_eq!(y, const_interval!(4.0));
+    /// assert_eq!(Interval::eval_poly(&[], const_interval!(3.0)), const_interval!(0.0));
+    /// ```
+    #[must_use]
+    pub fn eval_poly(coeffs: &[f64], x: Interval) -> Self {
+        if x.is_empty() {
+            return x;
+        }
+
+        let c = |c: f64| Self::from_bounds_lossy(c, c);
+        match coeffs.split_first() {
+            Some((&c0, rest)) => rest.iter().fold(c(c0), |y, &ci| y.mul_add(x, c(ci))),
+            None => Self::zero(),
+        }
+    }
+
+    /// Returns an enclosure of the range of the polynomial with the coefficients `coeffs`
+    /// over `x`, evaluated by Estrin's scheme.
+    ///
+    /// The coefficients are ordered from the highest-degree term to the constant term,
+    /// and an empty slice denotes the zero polynomial.
+    ///
+    /// Estrin's scheme combines pairs of adjacent terms as $c_{2i} + c_{2i+1} x$,
+    /// then pairs of the results with $x^2$, with $x^4$, and so on. The operations within each
+    /// level are independent, so the dependency chain is shorter than that of Horner's scheme,
+    /// which can be faster on targets with instruction-level parallelism.
+    /// The result is an enclosure as well, but since the operations are done in different order,
+    /// it can be slightly wider or narrower than the result of Horner's scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// // x^2 - 2x + 1
+    /// let coeffs = [const_interval!(1.0), const_interval!(-2.0), const_interval!(1.0)];
+    /// assert_eq!(Interval::eval_poly_estrin(const_interval!(3.0), &coeffs), const_interval!(4.0));
+    /// ```
+    #[must_use]
+    pub fn eval_poly_estrin(x: Interval, coeffs: &[Interval]) -> Self {
+        if x.is_empty() {
+            return x;
+        }
+
+        // Coefficients in ascending order of degree.
+        let mut cs = coeffs.iter().rev().copied().collect::<Vec<_>>();
+        let mut p = x;
+        while cs.len() > 1 {
+            let n = cs.len();
+            let m = n.div_ceil(2);
+            for i in 0..m {
+                cs[i] = if 2 * i + 1 < n {
+                    cs[2 * i] + cs[2 * i + 1] * p
+                } else {
+                    cs[2 * i]
+                };
+            }
+            cs.truncate(m);
+            p = p.sqr();
+        }
+
+        cs.first().copied().unwrap_or_else(Self::zero)
+    }
+
+    /// Returns the sum of the enclosures of the terms of a truncated power series
+    /// and the enclosure of its remainder:
+    ///
+    /// $$
+    /// \sum_{k=0}^{n-1} t_k + R.
+    /// $$
+    ///
+    /// If $t_k$ encloses the range of the $k$-th term of the Taylor expansion of a function
+    /// over a domain and $R$ encloses the range of the remainder term
+    /// (e.g., of the Lagrange form), the result encloses the range of the function.
+    /// The result is $∅$ if any of the terms or the remainder is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// // exp(0.5) ∈ 1 + 0.5 + 0.5^2/2 + R, R = e^ξ 0.5^3/6 ∈ [0.5^3/6, 2 × 0.5^3/6].
+    /// let terms = [const_interval!(1.0), const_interval!(0.5), const_interval!(0.125)];
+    /// let r = const_interval!(0.0208, 0.0417);
+    /// let y = Interval::taylor_sum_with_remainder(&terms, r);
+    /// assert!(y.contains(0.5_f64.exp()));
+    /// ```
+    #[must_use]
+    pub fn taylor_sum_with_remainder(terms: &[Interval], remainder_bound: Interval) -> Self {
+        terms.iter().fold(remainder_bound, |s, &t| s + t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn eval_rational() {
+        // (x^2 + 1) / (x + 2)
+        let num = [const_interval!(1.0), const_interval!(0.0), const_interval!(1.0)];
+        let den = [const_interval!(1.0), const_interval!(2.0)];
+        assert_eq!(I::eval_rational(const_interval!(0.0), &num, &den), const_interval!(0.5));
+        assert_eq!(I::eval_rational(const_interval!(2.0), &num, &den), const_interval!(1.25));
+
+        assert!(I::eval_rational(I::EMPTY, &num, &den).is_empty());
+        assert!(I::eval_rational(I::PI, &[I::EMPTY], &den).is_empty());
+        assert!(I::eval_rational(I::PI, &num, &[]).is_empty());
+        assert_eq!(I::eval_rational(I::PI, &[], &den), const_interval!(0.0));
+    }
+
+    #[test]
+    fn eval_rational_pole() {
+        // 1 / (x - 1)
+        let num = [const_interval!(1.0)];
+        let den = [const_interval!(1.0), const_interval!(-1.0)];
+
+        // The denominator straddles zero.
+        assert_eq!(I::eval_rational(const_interval!(0.9, 1.1), &num, &den), I::ENTIRE);
+
+        // The denominator touches zero from either side.
+        let y = I::eval_rational(const_interval!(1.0, 1.5), &num, &den);
+        assert_eq!(y, const_interval!(2.0, f64::INFINITY));
+        let y = I::eval_rational(const_interval!(0.5, 1.0), &num, &den);
+        assert_eq!(y, const_interval!(f64::NEG_INFINITY, -2.0));
+
+        // The denominator is exactly zero.
+        assert!(I::eval_rational(const_interval!(1.0), &num, &den).is_empty());
+    }
+
+    #[test]
+    fn taylor_sum_with_remainder() {
+        // exp(x) = Σ_{k=0}^{4} x^k/k! + e^ξ x^5/5!, ξ ∈ x, for x ∈ [-0.1, 0.1].
+        let x = const_interval!(-0.1, 0.1);
+        let mut terms = vec![];
+        let mut fact = 1.0;
+        for k in 0..5 {
+            if k > 0 {
+                fact *= k as f64;
+            }
+            terms.push(x.powi(k) / interval!(fact).unwrap());
+        }
+        let r = x.exp() * x.powi(5) / const_interval!(120.0);
+
+        let y = I::taylor_sum_with_remainder(&terms, r);
+        assert!(x.exp().subset(y));
+        assert!(y.sup - y.inf < 0.21);
+
+        assert_eq!(I::taylor_sum_with_remainder(&[], r), r);
+        assert!(I::taylor_sum_with_remainder(&terms, I::EMPTY).is_empty());
+        assert!(I::taylor_sum_with_remainder(&[I::EMPTY], r).is_empty());
+    }
+
+    #[test]
+    fn eval_poly() {
+        // 2x^5 - 4x^3 + x + 1, as in the test `main` of interval.rs.
+        let coeffs = [2.0, 0.0, -4.0, 0.0, 1.0, 1.0];
+        let f = |x: f64| 2.0 * x.powi(5) - 4.0 * x.powi(3) + x + 1.0;
+
+        for (a, b) in [(-1.2, 1.2), (0.0, 0.5), (-0.3, -0.1), (1.0, 1.0), (-2.0, 3.0)] {
+            let x = interval!(a, b).unwrap();
+            let y = I::eval_poly(&coeffs, x);
+            // f(t) is computed exactly for t = k/64 with |t| ≤ 3, as it needs at most 40 bits.
+            for k in (a * 64.0).ceil() as i64..=(b * 64.0).floor() as i64 {
+                let t = k as f64 / 64.0;
+                assert!(y.contains(f(t)), "{} ∌ f({})", y, t);
+            }
+        }
+
+        // ((((2x + 0)x - 4)x + 0)x + 1)x + 1 evaluated by hand, where every bound is exact:
+        // [0, 1] → [0, 0.5] → [-4, -3.5] → [-2, 0] → [-1, 0] → [0, 1] → [0, 0.5] → [1, 1.5].
+        assert_eq!(I::eval_poly(&coeffs, const_interval!(0.0, 0.5)), const_interval!(1.0, 1.5));
+        assert_eq!(I::eval_poly(&coeffs, const_interval!(1.0)), const_interval!(0.0));
+        assert_eq!(I::eval_poly(&coeffs, const_interval!(0.5)), const_interval!(1.0625));
+        // 10^-400 underflows.
+        let y = I::eval_poly(&[1e-200, 0.0], const_interval!(1e-200));
+        assert!(y.inf <= 0.0 && 0.0 < y.sup);
+
+        let x = const_interval!(-1.2, 1.2);
+
+        assert_eq!(I::eval_poly(&[], x), const_interval!(0.0));
+        assert_eq!(I::eval_poly(&[3.0], x), const_interval!(3.0));
+        assert!(I::eval_poly(&coeffs, I::EMPTY).is_empty());
+        assert!(I::eval_poly(&[], I::EMPTY).is_empty());
+        assert!(I::eval_poly(&[1.0, f64::NAN], x).is_empty());
+    }
+
+    #[test]
+    fn eval_poly_estrin() {
+        // 2x^5 - 4x^3 + x + 1
+        let coeffs = [2.0, 0.0, -4.0, 0.0, 1.0, 1.0].map(|c| interval!(c).unwrap());
+        let f = |x: f64| 2.0 * x.powi(5) - 4.0 * x.powi(3) + x + 1.0;
+
+        for (a, b) in [(-1.2, 1.2), (0.0, 0.5), (-0.3, -0.1), (1.0, 1.0), (-2.0, 3.0)] {
+            let x = interval!(a, b).unwrap();
+            let y = I::eval_poly_estrin(x, &coeffs);
+            assert!(!y.disjoint(horner(x, &coeffs)));
+            for i in 0..=100 {
+                let t = (a + (b - a) * i as f64 / 100.0).min(b);
+                assert!(y.contains(f(t)));
+            }
+        }
+
+        for n in 0..=8 {
+            let coeffs = vec![const_interval!(1.0); n];
+            let y = I::eval_poly_estrin(const_interval!(2.0), &coeffs);
+            assert_eq!(y, interval!(2f64.powi(n as i32) - 1.0).unwrap());
+        }
+
+        assert!(I::eval_poly_estrin(I::EMPTY, &coeffs).is_empty());
+        assert!(I::eval_poly_estrin(I::PI, &[I::EMPTY, I::PI]).is_empty());
+    }
+
+    #[test]
+    #[ignore = "benchmark; run with `cargo test --release -- --ignored`"]
+    pub fn benchmark_estrin() {
+        let n = 1_000_000;
+        // A degree-10 polynomial.
+        let coeffs = [1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0, 9.0, -10.0, 11.0].map(|c| interval!(c).unwrap());
+        let x = const_interval!(0.25, 0.5);
+
+        let mut sum = 0.0;
+        let start = std::time::Instant::now();
+        for _ in 0..n {
+            sum += horner(std::hint::black_box(x), &coeffs).inf;
+        }
+        let duration = start.elapsed();
+        println!("Horner: {:?} (sum = {})", duration, sum);
+
+        let mut sum = 0.0;
+        let start = std::time::Instant::now();
+        for _ in 0..n {
+            sum += I::eval_poly_estrin(std::hint::black_box(x), &coeffs).inf;
+        }
+        let duration = start.elapsed();
+        println!("Estrin: {:?} (sum = {})", duration, sum);
+    }
+}
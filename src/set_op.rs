@@ -1,4 +1,12 @@
-use crate::{interval::*};
+use crate::{const_interval, interval::*};
+
+// Returns the point that divides [a, b] in the ratio t : 1 - t for `t` ∈ [0, 1],
+// which is exactly `a` for t = 0 and `b` for t = 1.
+// The interpolation avoids the overflow of `b - a`, and the result is clamped to [a, b]
+// as it can be slightly off due to rounding errors.
+fn split_point(a: f64, b: f64, t: f64) -> f64 {
+    ((1.0 - t) * a + t * b).clamp(a, b)
+}
 
 impl Interval {
     /// Returns $\hull(\self ∪ \rhs)$, the tightest interval that contains both `self` and `rhs` as its subsets.
@@ -20,6 +28,27 @@ impl Interval {
         Self { inf: f64::min(self.inf, rhs.inf), sup: f64::max(self.sup, rhs.sup) }
     }
 
+    /// Returns the convex hull of all the intervals in `iter`, the tightest interval
+    /// that contains every one of them as its subset.
+    ///
+    /// Empty intervals are skipped since $∅$ is the identity of [`Interval::convex_hull`],
+    /// and the result is $∅$ if no nonempty interval is given.
+    /// The same is also available through the [`FromIterator`] impl.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let xs = vec![const_interval!(1.0, 2.0), Interval::EMPTY, const_interval!(5.0, 6.0)];
+    /// assert_eq!(Interval::hull_all(xs.clone()), const_interval!(1.0, 6.0));
+    /// assert_eq!(xs.into_iter().collect::<Interval>(), const_interval!(1.0, 6.0));
+    /// assert_eq!(Interval::hull_all(vec![]), Interval::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn hull_all<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::convex_hull)
+    }
+
     /// Returns $\self ∩ \rhs$, the intersection of `self` and `rhs`.
     ///
     /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$                      |
@@ -44,6 +73,502 @@ impl Interval {
             i
         }
     }
+
+    /// Returns the closure of the gap between `self` and `rhs`, if they are disjoint.
+    ///
+    /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$                                 |
+    /// | :----------------: | :--------: | :-----------------------------------------------: |
+    /// | $\self = ∅$        | $∅$        | $∅$                                               |
+    /// | $\self = \[a, b\]$ | $∅$        | $\[b, c\]$ if $b < c$, $\[d, a\]$ if $d < a$, $∅$ otherwise |
+    ///
+    /// The gap itself is the open interval between the two, which is enclosed by the result.
+    /// The result is $∅$ if the intervals overlap or only touch each other.
+    /// It does not depend on the order of the arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(0.0, 1.0);
+    /// assert_eq!(x.gap(const_interval!(3.0, 4.0)), const_interval!(1.0, 3.0));
+    /// assert_eq!(const_interval!(3.0, 4.0).gap(x), const_interval!(1.0, 3.0));
+    /// assert_eq!(x.gap(const_interval!(1.0, 4.0)), Interval::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn gap(self, rhs: Self) -> Self {
+        // The comparisons are false if either is empty.
+        if self.sup < rhs.inf {
+            // [b, c]
+            Self::with_infsup_raw(self.sup, rhs.inf)
+        } else if rhs.sup < self.inf {
+            // [d, a]
+            Self::with_infsup_raw(rhs.sup, self.inf)
+        } else {
+            Self::EMPTY
+        }
+    }
+
+    /// Returns the intersection of `self` and `domain`, paired with `true` if it differs from `self`,
+    /// i.e., some members of `self` are outside of `domain` and have been clipped off.
+    ///
+    /// This is useful for telling whether a function that restricts its argument to its domain,
+    /// such as [`Interval::sqrt`], has been evaluated outside of the domain.
+    /// If `self` is empty, the result is `(EMPTY, false)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let dom = const_interval!(0.0, f64::INFINITY);
+    /// assert_eq!(const_interval!(1.0, 2.0).clip_to(dom), (const_interval!(1.0, 2.0), false));
+    /// assert_eq!(const_interval!(-1.0, 2.0).clip_to(dom), (const_interval!(0.0, 2.0), true));
+    /// assert_eq!(const_interval!(-2.0, -1.0).clip_to(dom), (Interval::EMPTY, true));
+    /// ```
+    #[must_use]
+    pub fn clip_to(self, domain: Self) -> (Self, bool) {
+        (self.intersection(domain), !self.subset(domain))
+    }
+
+    /// Returns $\self ∩ \[\mathrm{lo}, \mathrm{hi}\]$, the part of `self` within `lo` and `hi`.
+    ///
+    /// Unlike [`f64::clamp`], members outside the bounds are dropped instead of being mapped
+    /// to the bounds, so the result is $∅$ if `self` lies entirely outside.
+    /// The bounds can be infinite; for example, `x.clamp(0.0, f64::INFINITY)` is the same as
+    /// [`x.clamp_nonneg()`](Interval::clamp_nonneg).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`, `lo` is NaN, or `hi` is NaN, as [`f64::clamp`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(-1.0, 2.0);
+    /// assert_eq!(x.clamp(0.0, 1.0), const_interval!(0.0, 1.0));
+    /// assert_eq!(x.clamp(0.0, 5.0), const_interval!(0.0, 2.0));
+    /// assert_eq!(x.clamp(3.0, 5.0), Interval::EMPTY);
+    /// ```
+    ///
+    /// See also: [`Interval::clamp_to`].
+    #[must_use]
+    pub fn clamp(self, lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "`lo` must be less than or equal to `hi`, and neither may be NaN");
+        // [+∞, +∞] and [-∞, -∞] contain no real numbers.
+        self.intersection(Self::from_bounds_lossy(lo, hi))
+    }
+
+    /// Returns $\self ∩ \mathrm{window}$, the part of `self` within `window`.
+    ///
+    /// This is the same as [`self.intersection(window)`](Interval::intersection),
+    /// and the counterpart of [`Interval::clamp`] that takes the bounds as an interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let window = const_interval!(0.0, 1.0);
+    /// assert_eq!(const_interval!(-1.0, 0.5).clamp_to(window), const_interval!(0.0, 0.5));
+    /// assert_eq!(const_interval!(2.0, 3.0).clamp_to(window), Interval::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn clamp_to(self, window: Self) -> Self {
+        self.intersection(window)
+    }
+
+    /// Returns $\self ∩ \[0, +∞\]$, the nonnegative part of `self`.
+    ///
+    /// Note the difference from `self.max(const_interval!(0.0))`, i.e., the ReLU function,
+    /// which maps negative numbers to zero instead of dropping them:
+    /// for $\self = \[-2, -1\]$, the ReLU gives $\[0, 0\]$, whereas this function gives $∅$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-1.0, 2.0).clamp_nonneg(), const_interval!(0.0, 2.0));
+    /// assert_eq!(const_interval!(-2.0, -1.0).clamp_nonneg(), Interval::EMPTY);
+    /// ```
+    ///
+    /// See also: [`Interval::clamp_nonpos`].
+    #[must_use]
+    pub fn clamp_nonneg(self) -> Self {
+        const NONNEG: Interval = const_interval!(0.0, f64::INFINITY);
+        self.intersection(NONNEG)
+    }
+
+    /// Returns $\self ∩ \[-∞, 0\]$, the nonpositive part of `self`.
+    ///
+    /// For $\self = \[1, 2\]$, the result is $∅$, whereas `self.min(const_interval!(0.0))`
+    /// gives $\[0, 0\]$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// assert_eq!(const_interval!(-1.0, 2.0).clamp_nonpos(), const_interval!(-1.0, 0.0));
+    /// assert_eq!(const_interval!(1.0, 2.0).clamp_nonpos(), Interval::EMPTY);
+    /// ```
+    ///
+    /// See also: [`Interval::clamp_nonneg`].
+    #[must_use]
+    pub fn clamp_nonpos(self) -> Self {
+        const NONPOS: Interval = const_interval!(f64::NEG_INFINITY, 0.0);
+        self.intersection(NONPOS)
+    }
+
+    /// Returns the tightest interval `z` such that `rhs + z` $⊇$ `self`,
+    /// i.e., the inverse of interval addition as defined by the IEEE 1788 `cancelMinus` operation.
+    ///
+    /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$ | $\rhs$ unbounded |
+    /// | :----------------: | :--------: | :---------------: | :--------------: |
+    /// | $\self = ∅$        | $∅$        | $∅$               | $\R$             |
+    /// | $\self = \[a, b\]$ | $\R$       | see below         | $\R$             |
+    /// | $\self$ unbounded  | $\R$       | $\R$              | $\R$             |
+    ///
+    /// For bounded $\self = \[a, b\]$ and $\rhs = \[c, d\]$, the result is $\[a - c, b - d\]$,
+    /// rounded outward, if $\wid \self ≥ \wid \rhs$, and $\R$ otherwise.
+    /// The widths are compared exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(-2.0, 2.0);
+    /// assert_eq!(x.cancel_minus(const_interval!(-1.0, 1.0)), const_interval!(-1.0, 1.0));
+    /// assert_eq!(x.cancel_minus(const_interval!(-3.0, 3.0)), Interval::ENTIRE);
+    /// assert_eq!(Interval::EMPTY.cancel_minus(x), Interval::EMPTY);
+    /// ```
+    ///
+    /// See also: [`Interval::cancel_plus`].
+    #[must_use]
+    pub fn cancel_minus(self, rhs: Self) -> Self {
+        if self.is_empty() && (rhs.is_empty() || rhs.is_common_interval()) {
+            return Self::EMPTY;
+        }
+        if !(self.is_common_interval() && rhs.is_common_interval()) {
+            return Self::ENTIRE;
+        }
+
+        let [a, b, c, d] = [self.inf, self.sup, rhs.inf, rhs.sup];
+
+        // wid(self) ≥ wid(rhs)  ⟺  b - d ≥ a - c.
+        // Rounding to nearest is monotonic, thus if the rounded differences differ,
+        // they are ordered as the exact ones. Otherwise, the error terms decide.
+        let (s1, e1) = crate::arith::two_sum(b, -d);
+        let (s2, e2) = crate::arith::two_sum(a, -c);
+        let wide_enough = if s1 != s2 {
+            s1 > s2
+        } else {
+            // The error terms are NaN if both differences have overflowed.
+            e1 >= e2
+        };
+        if !wide_enough {
+            return Self::ENTIRE;
+        }
+
+        Self::with_infsup_raw(crate::arith::add_down(a, -c), crate::arith::add_up(b, -d))
+    }
+
+    /// Returns the tightest interval `z` such that `z - rhs` $⊇$ `self`,
+    /// i.e., `self.cancel_minus(-rhs)`, the IEEE 1788 `cancelPlus` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(-2.0, 2.0);
+    /// assert_eq!(x.cancel_plus(const_interval!(-1.0, 1.0)), const_interval!(-1.0, 1.0));
+    /// assert_eq!(x.cancel_plus(const_interval!(0.0, 1.0)), const_interval!(-1.0, 2.0));
+    /// ```
+    ///
+    /// See also: [`Interval::cancel_minus`].
+    #[must_use]
+    pub fn cancel_plus(self, rhs: Self) -> Self {
+        self.cancel_minus(-rhs)
+    }
+
+    /// Applies `f` to each member of `xs` and returns the results,
+    /// paired with `true` if `f` has reported clipping for any of the members.
+    ///
+    /// `f` is expected to return the result paired with a flag as [`Interval::clip_to`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let sqrt_checked = |x: Interval| {
+    ///     let (x, clipped) = x.clip_to(const_interval!(0.0, f64::INFINITY));
+    ///     (x.sqrt(), clipped)
+    /// };
+    /// let xs = [const_interval!(1.0, 4.0), const_interval!(-1.0, 4.0)];
+    /// let (ys, clipped) = Interval::map_checked(&xs, sqrt_checked);
+    /// assert_eq!(ys, vec![const_interval!(1.0, 2.0), const_interval!(0.0, 2.0)]);
+    /// assert!(clipped);
+    /// ```
+    pub fn map_checked<F: Fn(Interval) -> (Interval, bool)>(xs: &[Interval], f: F) -> (Vec<Interval>, bool) {
+        let mut any_clipped = false;
+        let ys = xs
+            .iter()
+            .map(|&x| {
+                let (y, clipped) = f(x);
+                any_clipped |= clipped;
+                y
+            })
+            .collect();
+        (ys, any_clipped)
+    }
+}
+
+impl Interval {
+    /// Splits `self` at `x` into $(\self ∩ \[-∞, x\], \self ∩ \[x, +∞\])$.
+    ///
+    /// If $x ∈ \self$, both pieces contain `x`. Otherwise, one of them is `self`
+    /// and the other is $∅$. Both pieces are $∅$ if `self` is empty or `x` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(0.0, 4.0);
+    /// assert_eq!(x.split_at(1.0), (const_interval!(0.0, 1.0), const_interval!(1.0, 4.0)));
+    /// assert_eq!(x.split_at(5.0), (x, Interval::EMPTY));
+    /// ```
+    #[must_use]
+    pub fn split_at(self, x: f64) -> (Self, Self) {
+        if self.is_empty() || x.is_nan() {
+            return (Self::EMPTY, Self::EMPTY);
+        }
+
+        let (a, b) = (self.inf, self.sup);
+        // No real number lies at ±∞.
+        let lo = if x < a || x == f64::NEG_INFINITY {
+            Self::EMPTY
+        } else {
+            Self::with_infsup_raw(a, f64::min(b, x))
+        };
+        let hi = if x > b || x == f64::INFINITY {
+            Self::EMPTY
+        } else {
+            Self::with_infsup_raw(f64::max(a, x), b)
+        };
+        (lo, hi)
+    }
+
+    /// Splits `self` into two intervals at the point $a + r (b - a)$, where $\self = \[a, b\]$
+    /// and $r$ is `ratio`.
+    ///
+    /// Both pieces contain the split point, which is identical to the upper bound of the first piece
+    /// and the lower bound of the second one, so that their union is exactly `self`.
+    ///
+    /// - If `self` is unbounded, `ratio` is ignored and `self` is split at
+    ///   [`self.mid()`](`Interval::mid`), which is finite.
+    /// - If `self` is empty, the result is `(EMPTY, EMPTY)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is not in $(0, 1)$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(0.0, 1.0);
+    /// assert_eq!(x.bisect(0.5), (const_interval!(0.0, 0.5), const_interval!(0.5, 1.0)));
+    /// assert_eq!(x.bisect(0.25), (const_interval!(0.0, 0.25), const_interval!(0.25, 1.0)));
+    /// ```
+    #[must_use]
+    pub fn bisect(self, ratio: f64) -> (Self, Self) {
+        assert!(0.0 < ratio && ratio < 1.0, "`ratio` must be in (0, 1)");
+
+        if self.is_empty() {
+            return (Self::EMPTY, Self::EMPTY);
+        }
+
+        let (a, b) = (self.inf, self.sup);
+        let x = if self.is_common_interval() {
+            split_point(a, b, ratio)
+        } else {
+            self.mid()
+        };
+        self.split_at(x)
+    }
+
+    /// Splits `self` into `n` subintervals of approximately equal width.
+    ///
+    /// The pieces are ordered from left to right and abut each other: the upper bound of each piece
+    /// is identical to the lower bound of the next one, so that their union is exactly `self`.
+    /// Pieces can be singletons if `self` is too narrow to be split into `n` distinct pieces.
+    ///
+    /// - If `n` is zero, the result is an empty [`Vec`].
+    /// - If `self` is empty or unbounded, it cannot be split into pieces of equal width,
+    ///   and the result is `vec![self]` regardless of `n`, unless `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let xs = const_interval!(0.0, 1.0).mince(4);
+    /// assert_eq!(xs, vec![
+    ///     const_interval!(0.0, 0.25),
+    ///     const_interval!(0.25, 0.5),
+    ///     const_interval!(0.5, 0.75),
+    ///     const_interval!(0.75, 1.0),
+    /// ]);
+    /// assert_eq!(Interval::ENTIRE.mince(4), vec![Interval::ENTIRE]);
+    /// ```
+    #[must_use]
+    pub fn mince(self, n: usize) -> Vec<Interval> {
+        if n == 0 {
+            return vec![];
+        }
+        if n == 1 || !self.is_common_interval() {
+            return vec![self];
+        }
+
+        let (a, b) = (self.inf, self.sup);
+        let mut pieces = Vec::with_capacity(n);
+        let mut lo = a;
+        for i in 1..=n {
+            // `max` keeps the points nondecreasing despite rounding errors.
+            let hi = f64::max(lo, split_point(a, b, i as f64 / n as f64));
+            pieces.push(Self::with_infsup_raw(lo, hi));
+            lo = hi;
+        }
+        pieces
+    }
+
+    /// Merges the members of `intervals` that overlap or are separated by a gap
+    /// of at most `gap_tol`, replacing each group with its convex hull.
+    ///
+    /// Empty intervals are removed. Afterwards, `intervals` is sorted in ascending order,
+    /// and the gap between any two adjacent members is greater than `gap_tol`.
+    /// With `gap_tol = 0.0`, only overlapping or touching intervals are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let mut xs = vec![const_interval!(3.0, 4.0), const_interval!(0.0, 1.0), const_interval!(1.1, 2.0)];
+    /// Interval::union_within(&mut xs, 0.2);
+    /// assert_eq!(xs, vec![const_interval!(0.0, 2.0), const_interval!(3.0, 4.0)]);
+    /// ```
+    pub fn union_within(intervals: &mut Vec<Interval>, gap_tol: f64) {
+        intervals.retain(|x| !x.is_empty());
+        intervals.sort_by(|x, y| x.inf.partial_cmp(&y.inf).unwrap());
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for &x in intervals.iter() {
+            match merged.last_mut() {
+                Some(last) if x.inf - last.sup <= gap_tol => *last = last.convex_hull(x),
+                _ => merged.push(x),
+            }
+        }
+        *intervals = merged;
+    }
+
+    /// Returns the pieces of `self` that remain after removing the members of `rhs`:
+    /// $\self ∖ \rhs$, which consists of zero, one or two intervals.
+    ///
+    /// The pieces are returned in ascending order, and unused slots are filled with $∅$:
+    ///
+    /// - `(EMPTY, EMPTY)` if `rhs` covers `self`, or `self` is empty,
+    /// - `(self, EMPTY)` if `rhs` is disjoint from `self`, or `rhs` is empty,
+    /// - `(lo, EMPTY)` or `(hi, EMPTY)` if `rhs` covers one end of `self`,
+    /// - `(lo, hi)` if `rhs` lies strictly inside `self`.
+    ///
+    /// As with [`Interval::subtract_all`], the pieces are closed intervals,
+    /// thus they contain the bounds of `rhs` at the cut points, which do not belong to $\self ∖ \rhs$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(0.0, 10.0);
+    /// assert_eq!(
+    ///     x.difference(const_interval!(3.0, 5.0)),
+    ///     (const_interval!(0.0, 3.0), const_interval!(5.0, 10.0))
+    /// );
+    /// assert_eq!(x.difference(const_interval!(8.0, 12.0)), (const_interval!(0.0, 8.0), Interval::EMPTY));
+    /// assert_eq!(x.difference(const_interval!(20.0, 30.0)), (x, Interval::EMPTY));
+    /// assert_eq!(x.difference(Interval::ENTIRE), (Interval::EMPTY, Interval::EMPTY));
+    /// ```
+    #[must_use]
+    pub fn difference(self, rhs: Self) -> (Self, Self) {
+        let mut pieces = self.subtract_all(&[rhs]).into_iter();
+        let lo = pieces.next().unwrap_or(Self::EMPTY);
+        let hi = pieces.next().unwrap_or(Self::EMPTY);
+        (lo, hi)
+    }
+
+    /// Returns the pieces of `self` that remain after removing all members of `holes`,
+    /// as a sorted list of pairwise disjoint intervals.
+    ///
+    /// The holes may overlap or touch each other, and empty holes are ignored.
+    /// Since the pieces are closed intervals, each of them encloses a part of
+    /// $\self ∖ \bigcup_i \mathrm{holes}_i$ that is open at the ends adjacent to a hole;
+    /// e.g., removing $\[1, 2\]$ from $\[0, 3\]$ results in $\[0, 1\]$ and $\[2, 3\]$.
+    /// Pieces that would consist only of an endpoint of a hole are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let x = const_interval!(0.0, 10.0);
+    /// let holes = [const_interval!(4.0, 6.0), const_interval!(1.0, 2.0), const_interval!(5.0, 7.0)];
+    /// assert_eq!(
+    ///     x.subtract_all(&holes),
+    ///     vec![const_interval!(0.0, 1.0), const_interval!(2.0, 4.0), const_interval!(7.0, 10.0)]
+    /// );
+    /// ```
+    pub fn subtract_all(self, holes: &[Interval]) -> Vec<Interval> {
+        let mut pieces = vec![];
+        if self.is_empty() {
+            return pieces;
+        }
+
+        let mut holes = holes.iter().filter(|h| !h.is_empty()).copied().collect::<Vec<_>>();
+        holes.sort_by(|x, y| x.inf.partial_cmp(&y.inf).unwrap());
+
+        // Sweep from left to right. `start` is the left end of the next piece,
+        // and `covered` is whether `start` itself belongs to a hole.
+        let mut start = self.inf;
+        let mut covered = false;
+        for h in holes {
+            if h.inf > self.sup {
+                break;
+            }
+            if h.sup < start {
+                continue;
+            }
+            if h.inf > start {
+                pieces.push(Self::with_infsup_raw(start, h.inf));
+            }
+            start = h.sup;
+            covered = true;
+        }
+
+        if start < self.sup || !covered && start == self.sup {
+            pieces.push(Self::with_infsup_raw(start, self.sup));
+        }
+
+        pieces
+    }
+}
+
+impl FromIterator<Interval> for Interval {
+    /// Returns the convex hull of the intervals, which is $∅$ for an empty iterator.
+    ///
+    /// See [`Interval::hull_all`].
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        Self::hull_all(iter)
+    }
+}
+
+impl<'a> FromIterator<&'a Interval> for Interval {
+    fn from_iter<I: IntoIterator<Item = &'a Interval>>(iter: I) -> Self {
+        Self::hull_all(iter.into_iter().copied())
+    }
 }
 
 #[cfg(test)]
@@ -59,4 +584,401 @@ mod tests {
         assert!(I::EMPTY.intersection(I::PI).is_empty());
         assert!(I::PI.intersection(I::EMPTY).is_empty());
     }
+
+    #[test]
+    fn cancel_minus() {
+        let x = const_interval!(-2.0, 2.0);
+        let y = const_interval!(-1.0, 1.0);
+        assert_eq!(x.cancel_minus(y), y);
+        assert_eq!(x.cancel_minus(x), const_interval!(0.0, 0.0));
+        assert_eq!(
+            const_interval!(-10.0, -1.0).cancel_minus(const_interval!(-5.0, -1.0)),
+            const_interval!(-5.0, 0.0)
+        );
+        assert_eq!(const_interval!(1.0, 2.0).cancel_minus(const_interval!(-1.0, 5.0)), I::ENTIRE);
+
+        // Empty and unbounded operands.
+        assert_eq!(I::EMPTY.cancel_minus(I::EMPTY), I::EMPTY);
+        assert_eq!(I::EMPTY.cancel_minus(y), I::EMPTY);
+        assert_eq!(I::EMPTY.cancel_minus(I::ENTIRE), I::ENTIRE);
+        assert_eq!(y.cancel_minus(I::EMPTY), I::ENTIRE);
+        assert_eq!(I::ENTIRE.cancel_minus(y), I::ENTIRE);
+        assert_eq!(y.cancel_minus(const_interval!(0.0, f64::INFINITY)), I::ENTIRE);
+
+        // The widths are compared exactly: 2 - (2 - 2^-60) > 0 and 2 - (2 + 2^-60) < 0,
+        // while both differences round to 2 - 2 = 0.
+        let tiny = 2.0f64.powi(-60);
+        let x = const_interval!(1.0, 3.0);
+        let z = x.cancel_minus(interval!(tiny, 2.0).unwrap());
+        assert_eq!(z, interval!(1.0f64.next_down(), 1.0).unwrap());
+        assert_eq!(x.cancel_minus(interval!(-tiny, 2.0).unwrap()), I::ENTIRE);
+
+        // Outward rounding: y + z ⊇ x.
+        let x = const_interval!(1.0, 2.0);
+        let y = const_interval!(0.1, 0.3);
+        let z = x.cancel_minus(y);
+        assert!(x.subset(y + z));
+        assert!(z.inf < z.sup);
+
+        assert_eq!(x.cancel_plus(-y), z);
+        assert_eq!(I::EMPTY.cancel_plus(y), I::EMPTY);
+        assert_eq!(y.cancel_plus(I::EMPTY), I::ENTIRE);
+    }
+
+    #[test]
+    fn gap() {
+        let x = const_interval!(0.0, 1.0);
+
+        // Disjoint, in either order.
+        for y in [const_interval!(3.0, 4.0), const_interval!(3.0, f64::INFINITY), const_interval!(3.0)] {
+            assert_eq!(x.gap(y), const_interval!(1.0, 3.0));
+            assert_eq!(y.gap(x), const_interval!(1.0, 3.0));
+        }
+        let y = const_interval!(f64::NEG_INFINITY, -2.0);
+        assert_eq!(x.gap(y), const_interval!(-2.0, 0.0));
+        assert_eq!(y.gap(x), const_interval!(-2.0, 0.0));
+
+        // Overlapping or touching.
+        assert!(x.gap(const_interval!(0.5, 2.0)).is_empty());
+        assert!(x.gap(const_interval!(1.0, 2.0)).is_empty());
+        assert!(const_interval!(1.0, 2.0).gap(x).is_empty());
+        assert!(x.gap(x).is_empty());
+        assert!(x.gap(I::ENTIRE).is_empty());
+
+        assert!(x.gap(I::EMPTY).is_empty());
+        assert!(I::EMPTY.gap(x).is_empty());
+        assert!(I::EMPTY.gap(I::EMPTY).is_empty());
+    }
+
+    #[test]
+    fn hull_all() {
+        let xs = [const_interval!(1.0, 2.0), const_interval!(5.0, 6.0), const_interval!(-1.0, 0.0)];
+        assert_eq!(I::hull_all(xs), const_interval!(-1.0, 6.0));
+        assert_eq!(xs.iter().collect::<I>(), const_interval!(-1.0, 6.0));
+        assert_eq!(xs.into_iter().collect::<I>(), const_interval!(-1.0, 6.0));
+        assert_eq!(I::hull_all([const_interval!(1.0, 2.0)]), const_interval!(1.0, 2.0));
+
+        // Empty intervals are skipped.
+        assert_eq!(I::hull_all([I::EMPTY, const_interval!(1.0, 2.0), I::EMPTY]), const_interval!(1.0, 2.0));
+        assert_eq!(I::hull_all([I::EMPTY, I::EMPTY]), I::EMPTY);
+
+        assert_eq!(
+            I::hull_all([const_interval!(0.0, f64::INFINITY), const_interval!(-1.0)]),
+            const_interval!(-1.0, f64::INFINITY)
+        );
+        assert_eq!(I::hull_all(std::iter::empty()), I::EMPTY);
+        assert_eq!(std::iter::empty::<I>().collect::<I>(), I::EMPTY);
+    }
+
+    #[test]
+    fn clip_to() {
+        let dom = const_interval!(-1.0, 1.0);
+
+        assert_eq!(const_interval!(-0.5, 0.5).clip_to(dom), (const_interval!(-0.5, 0.5), false));
+        assert_eq!(dom.clip_to(dom), (dom, false));
+
+        assert_eq!(const_interval!(0.5, 2.0).clip_to(dom), (const_interval!(0.5, 1.0), true));
+        assert_eq!(const_interval!(-2.0, 0.5).clip_to(dom), (const_interval!(-1.0, 0.5), true));
+        assert_eq!(I::ENTIRE.clip_to(dom), (dom, true));
+
+        let (x, clipped) = const_interval!(2.0, 3.0).clip_to(dom);
+        assert!(x.is_empty() && clipped);
+        let (x, clipped) = const_interval!(0.0).clip_to(I::EMPTY);
+        assert!(x.is_empty() && clipped);
+
+        let (x, clipped) = I::EMPTY.clip_to(dom);
+        assert!(x.is_empty() && !clipped);
+    }
+
+    #[test]
+    fn clamp() {
+        let x = const_interval!(-1.0, 2.0);
+
+        // Partial clipping.
+        assert_eq!(x.clamp(0.0, 1.0), const_interval!(0.0, 1.0));
+        assert_eq!(x.clamp(0.0, 5.0), const_interval!(0.0, 2.0));
+        assert_eq!(x.clamp(-5.0, 0.0), const_interval!(-1.0, 0.0));
+        assert_eq!(x.clamp(2.0, 2.0), const_interval!(2.0));
+        assert_eq!(I::ENTIRE.clamp(-1.0, 1.0), const_interval!(-1.0, 1.0));
+
+        // No clipping.
+        assert_eq!(x.clamp(-1.0, 2.0), x);
+        assert_eq!(x.clamp(f64::NEG_INFINITY, f64::INFINITY), x);
+        assert_eq!(x.clamp(0.0, f64::INFINITY), x.clamp_nonneg());
+
+        // Full clipping.
+        assert!(x.clamp(3.0, 5.0).is_empty());
+        assert!(x.clamp(-5.0, -3.0).is_empty());
+        assert!(I::ENTIRE.clamp(f64::INFINITY, f64::INFINITY).is_empty());
+        assert!(I::EMPTY.clamp(0.0, 1.0).is_empty());
+
+        for w in [const_interval!(0.0, 1.0), const_interval!(3.0, 5.0), I::ENTIRE, I::EMPTY] {
+            assert_eq!(x.clamp_to(w), x.intersection(w));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_invalid_bounds() {
+        let _ = const_interval!(-1.0, 2.0).clamp(1.0, 0.0);
+    }
+
+    #[test]
+    fn clamp_nonneg_nonpos() {
+        let zero = const_interval!(0.0);
+
+        assert_eq!(const_interval!(1.0, 2.0).clamp_nonneg(), const_interval!(1.0, 2.0));
+        assert_eq!(const_interval!(-1.0, 2.0).clamp_nonneg(), const_interval!(0.0, 2.0));
+        assert_eq!(const_interval!(-1.0, 0.0).clamp_nonneg(), zero);
+        assert_eq!(I::ENTIRE.clamp_nonneg(), const_interval!(0.0, f64::INFINITY));
+
+        assert_eq!(const_interval!(-2.0, -1.0).clamp_nonpos(), const_interval!(-2.0, -1.0));
+        assert_eq!(const_interval!(-1.0, 2.0).clamp_nonpos(), const_interval!(-1.0, 0.0));
+        assert_eq!(const_interval!(0.0, 1.0).clamp_nonpos(), zero);
+        assert_eq!(I::ENTIRE.clamp_nonpos(), const_interval!(f64::NEG_INFINITY, 0.0));
+
+        // Intersection drops the members on the other side, while max/min (ReLU) maps them to zero.
+        let neg = const_interval!(-2.0, -1.0);
+        assert!(neg.clamp_nonneg().is_empty());
+        assert_eq!(neg.max(zero), zero);
+        let pos = const_interval!(1.0, 2.0);
+        assert!(pos.clamp_nonpos().is_empty());
+        assert_eq!(pos.min(zero), zero);
+
+        assert!(I::EMPTY.clamp_nonneg().is_empty());
+        assert!(I::EMPTY.clamp_nonpos().is_empty());
+    }
+
+    #[test]
+    fn map_checked() {
+        let dom = const_interval!(-1.0, 1.0);
+        let f = |x: Interval| x.clip_to(dom);
+
+        let xs = [const_interval!(0.0, 0.5), const_interval!(-0.5, 1.0)];
+        assert_eq!(I::map_checked(&xs, f), (xs.to_vec(), false));
+
+        let xs = [const_interval!(0.0, 0.5), const_interval!(0.5, 2.0), const_interval!(-0.5)];
+        let (ys, clipped) = I::map_checked(&xs, f);
+        assert_eq!(ys, vec![const_interval!(0.0, 0.5), const_interval!(0.5, 1.0), const_interval!(-0.5)]);
+        assert!(clipped);
+
+        let (ys, clipped) = I::map_checked(&[const_interval!(3.0), I::EMPTY], f);
+        assert!(ys.iter().all(|y| y.is_empty()) && clipped);
+
+        assert_eq!(I::map_checked(&[], f), (vec![], false));
+    }
+
+    #[test]
+    fn split_at() {
+        let x = const_interval!(-1.0, 3.0);
+
+        // Inside.
+        assert_eq!(x.split_at(0.0), (const_interval!(-1.0, 0.0), const_interval!(0.0, 3.0)));
+        assert_eq!(x.split_at(2.5), (const_interval!(-1.0, 2.5), const_interval!(2.5, 3.0)));
+
+        // At a boundary.
+        assert_eq!(x.split_at(-1.0), (const_interval!(-1.0), x));
+        assert_eq!(x.split_at(3.0), (x, const_interval!(3.0)));
+
+        // Outside.
+        assert_eq!(x.split_at(-2.0), (I::EMPTY, x));
+        assert_eq!(x.split_at(4.0), (x, I::EMPTY));
+        assert_eq!(x.split_at(f64::NEG_INFINITY), (I::EMPTY, x));
+        assert_eq!(x.split_at(f64::INFINITY), (x, I::EMPTY));
+
+        assert_eq!(
+            I::ENTIRE.split_at(0.0),
+            (const_interval!(f64::NEG_INFINITY, 0.0), const_interval!(0.0, f64::INFINITY))
+        );
+        assert_eq!(I::ENTIRE.split_at(f64::NEG_INFINITY), (I::EMPTY, I::ENTIRE));
+        assert_eq!(I::ENTIRE.split_at(f64::INFINITY), (I::ENTIRE, I::EMPTY));
+        assert_eq!(I::EMPTY.split_at(0.0), (I::EMPTY, I::EMPTY));
+        assert_eq!(x.split_at(f64::NAN), (I::EMPTY, I::EMPTY));
+    }
+
+    #[test]
+    fn bisect() {
+        let x = const_interval!(0.0, 1.0);
+        assert_eq!(x.bisect(0.5), (const_interval!(0.0, 0.5), const_interval!(0.5, 1.0)));
+
+        for x in [
+            const_interval!(0.0, 1.0),
+            const_interval!(-0.1, 0.7),
+            const_interval!(f64::MIN, f64::MAX),
+            const_interval!(1.0, 1.0000000000000002),
+            const_interval!(2.0),
+            I::ENTIRE,
+            const_interval!(-1.0, f64::INFINITY),
+            const_interval!(f64::NEG_INFINITY, 1.0),
+        ] {
+            for r in [0.5, 0.1, 0.9, 1e-300, 1.0 - f64::EPSILON] {
+                let (y, z) = x.bisect(r);
+                assert_eq!(y.convex_hull(z), x);
+                assert_eq!(y.sup, z.inf);
+                assert!(y.sup.is_finite());
+            }
+        }
+
+        assert_eq!(
+            I::ENTIRE.bisect(0.1),
+            (const_interval!(f64::NEG_INFINITY, 0.0), const_interval!(0.0, f64::INFINITY))
+        );
+        assert_eq!(I::EMPTY.bisect(0.5), (I::EMPTY, I::EMPTY));
+    }
+
+    #[test]
+    #[should_panic]
+    fn bisect_invalid_ratio() {
+        let _ = const_interval!(0.0, 1.0).bisect(1.0);
+    }
+
+    #[test]
+    fn mince() {
+        let check = |x: I, n: usize| {
+            let xs = x.mince(n);
+            assert_eq!(xs.len(), n);
+            assert_eq!(xs.iter().fold(I::EMPTY, |h, &y| h.convex_hull(y)), x);
+            assert_eq!(xs[0].inf, x.inf);
+            assert_eq!(xs[n - 1].sup, x.sup);
+            for w in xs.windows(2) {
+                assert_eq!(w[0].sup, w[1].inf);
+            }
+        };
+        for x in [
+            const_interval!(0.0, 1.0),
+            const_interval!(-0.1, 0.7),
+            const_interval!(1e-300, 3e-300),
+            const_interval!(f64::MIN, f64::MAX),
+            const_interval!(1.0, 1.0000000000000004),
+            const_interval!(2.0),
+        ] {
+            for n in [1, 2, 3, 7, 10, 100] {
+                check(x, n);
+            }
+        }
+
+        assert_eq!(const_interval!(0.0, 3.0).mince(3), vec![
+            const_interval!(0.0, 1.0),
+            const_interval!(1.0, 2.0),
+            const_interval!(2.0, 3.0),
+        ]);
+        assert_eq!(const_interval!(0.0, 1.0).mince(0), vec![]);
+        assert_eq!(I::EMPTY.mince(0), vec![]);
+        assert_eq!(I::EMPTY.mince(3), vec![I::EMPTY]);
+        assert_eq!(I::ENTIRE.mince(3), vec![I::ENTIRE]);
+        let x = const_interval!(0.0, f64::INFINITY);
+        assert_eq!(x.mince(3), vec![x]);
+    }
+
+    #[test]
+    fn union_within() {
+        let xs = vec![
+            const_interval!(5.0, 6.0),
+            const_interval!(0.0, 1.0),
+            const_interval!(1.5, 2.0),
+            I::EMPTY,
+            const_interval!(2.0, 3.0),
+            const_interval!(2.5, 2.7),
+        ];
+
+        // Only overlapping or touching intervals are merged without tolerance.
+        let mut ys = xs.clone();
+        I::union_within(&mut ys, 0.0);
+        assert_eq!(ys, vec![const_interval!(0.0, 1.0), const_interval!(1.5, 3.0), const_interval!(5.0, 6.0)]);
+
+        // The gap of 0.5 is bridged, but not the gap of 2.
+        let mut ys = xs.clone();
+        I::union_within(&mut ys, 0.5);
+        assert_eq!(ys, vec![const_interval!(0.0, 3.0), const_interval!(5.0, 6.0)]);
+
+        let mut ys = xs.clone();
+        I::union_within(&mut ys, 2.0);
+        assert_eq!(ys, vec![const_interval!(0.0, 6.0)]);
+
+        let mut ys = vec![const_interval!(f64::NEG_INFINITY, 0.0), const_interval!(1.0, f64::INFINITY)];
+        I::union_within(&mut ys, 1.0);
+        assert_eq!(ys, vec![I::ENTIRE]);
+
+        let mut ys = vec![I::EMPTY];
+        I::union_within(&mut ys, 1.0);
+        assert!(ys.is_empty());
+    }
+
+    #[test]
+    fn difference() {
+        let x = const_interval!(0.0, 10.0);
+        let e = I::EMPTY;
+
+        // Inside.
+        assert_eq!(x.difference(const_interval!(3.0, 5.0)), (const_interval!(0.0, 3.0), const_interval!(5.0, 10.0)));
+        assert_eq!(x.difference(const_interval!(5.0)), (const_interval!(0.0, 5.0), const_interval!(5.0, 10.0)));
+
+        // Covering one end.
+        assert_eq!(x.difference(const_interval!(-1.0, 3.0)), (const_interval!(3.0, 10.0), e));
+        assert_eq!(x.difference(const_interval!(0.0, 3.0)), (const_interval!(3.0, 10.0), e));
+        assert_eq!(x.difference(const_interval!(8.0, 12.0)), (const_interval!(0.0, 8.0), e));
+        assert_eq!(x.difference(const_interval!(8.0, 10.0)), (const_interval!(0.0, 8.0), e));
+
+        // Outside or touching.
+        assert_eq!(x.difference(const_interval!(20.0, 30.0)), (x, e));
+        assert_eq!(x.difference(const_interval!(-2.0, -1.0)), (x, e));
+        assert_eq!(x.difference(const_interval!(10.0, 11.0)), (const_interval!(0.0, 10.0), e));
+        assert_eq!(x.difference(e), (x, e));
+
+        // Covering.
+        assert_eq!(x.difference(x), (e, e));
+        assert_eq!(x.difference(I::ENTIRE), (e, e));
+        assert_eq!(const_interval!(5.0).difference(const_interval!(5.0)), (e, e));
+        assert_eq!(e.difference(x), (e, e));
+
+        assert_eq!(
+            I::ENTIRE.difference(const_interval!(0.0, 1.0)),
+            (const_interval!(f64::NEG_INFINITY, 0.0), const_interval!(1.0, f64::INFINITY))
+        );
+    }
+
+    #[test]
+    fn subtract_all() {
+        let x = const_interval!(0.0, 10.0);
+        let c = |a, b| interval!(a, b).unwrap();
+
+        assert_eq!(x.subtract_all(&[]), vec![x]);
+        assert_eq!(x.subtract_all(&[I::EMPTY]), vec![x]);
+        assert_eq!(x.subtract_all(&[c(20.0, 30.0), c(-5.0, -1.0)]), vec![x]);
+
+        // Overlapping holes.
+        assert_eq!(
+            x.subtract_all(&[c(2.0, 5.0), c(3.0, 4.0), c(4.0, 6.0)]),
+            vec![c(0.0, 2.0), c(6.0, 10.0)]
+        );
+        // Touching holes.
+        assert_eq!(
+            x.subtract_all(&[c(5.0, 6.0), c(2.0, 5.0)]),
+            vec![c(0.0, 2.0), c(6.0, 10.0)]
+        );
+        // Holes sticking out of `self`.
+        assert_eq!(
+            x.subtract_all(&[c(-1.0, 1.0), c(9.0, 11.0), c(4.0, 5.0)]),
+            vec![c(1.0, 4.0), c(5.0, 9.0)]
+        );
+        // Holes touching the ends of `self`.
+        assert_eq!(x.subtract_all(&[c(-1.0, 0.0), c(10.0, 11.0)]), vec![x]);
+        assert_eq!(x.subtract_all(&[c(0.0, 1.0), c(9.0, 10.0)]), vec![c(1.0, 9.0)]);
+        // Singleton holes.
+        assert_eq!(
+            x.subtract_all(&[c(3.0, 3.0), c(3.0, 3.0)]),
+            vec![c(0.0, 3.0), c(3.0, 10.0)]
+        );
+        // Fully covered.
+        assert_eq!(x.subtract_all(&[c(-1.0, 4.0), c(3.0, 11.0)]), vec![]);
+        assert_eq!(x.subtract_all(&[I::ENTIRE]), vec![]);
+
+        assert_eq!(c(1.0, 1.0).subtract_all(&[c(2.0, 3.0)]), vec![c(1.0, 1.0)]);
+        assert_eq!(c(1.0, 1.0).subtract_all(&[c(0.0, 1.0)]), vec![]);
+        assert_eq!(
+            I::ENTIRE.subtract_all(&[c(0.0, 1.0)]),
+            vec![c(f64::NEG_INFINITY, 0.0), c(1.0, f64::INFINITY)]
+        );
+        assert_eq!(I::EMPTY.subtract_all(&[c(0.0, 1.0)]), vec![]);
+    }
 }
@@ -0,0 +1,378 @@
+use crate::{const_interval, interval::*};
+
+impl Interval {
+    /// Returns a pair of intervals that together enclose every real root $t$ of
+    ///
+    /// $$
+    /// a t^2 + b t + c = 0
+    /// $$
+    ///
+    /// for all choices of the coefficients from the intervals `a`, `b` and `c`.
+    ///
+    /// The roots are computed from the discriminant $d = b^2 - 4ac$
+    /// in the numerically stable form $q = -(b + \operatorname{sgn}(b) \sqrt d) / 2$,
+    /// $t_1 = q / a$, $t_2 = c / q$, and are returned in ascending order of the lower bounds.
+    ///
+    /// - If any coefficient is empty or the discriminant is provably negative,
+    ///   the result is `(EMPTY, EMPTY)`.
+    /// - If `a` is $\[0, 0\]$, the equation is linear. The result is `(ENTIRE, EMPTY)`
+    ///   if both `b` and `c` contain zero, since every $t$ is a root for $b = c = 0$,
+    ///   and `(-c / b, EMPTY)` otherwise.
+    /// - If `a` contains zero but is not $\[0, 0\]$, one of the roots can be arbitrarily large,
+    ///   and the result is `(ENTIRE, EMPTY)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// // t^2 - 3t + 2 = (t - 1)(t - 2)
+    /// let (t1, t2) = Interval::quadratic_roots(
+    ///     const_interval!(1.0),
+    ///     const_interval!(-3.0),
+    ///     const_interval!(2.0),
+    /// );
+    /// assert!(t1.contains(1.0) && t2.contains(2.0));
+    /// ```
+    #[must_use]
+    pub fn quadratic_roots(a: Self, b: Self, c: Self) -> (Self, Self) {
+        const TWO: Interval = const_interval!(2.0);
+        const FOUR: Interval = const_interval!(4.0);
+
+        if a.is_empty() || b.is_empty() || c.is_empty() {
+            return (Self::EMPTY, Self::EMPTY);
+        }
+
+        if a.inf == 0.0 && a.sup == 0.0 {
+            // b t + c = 0
+            if b.contains(0.0) && c.contains(0.0) {
+                return (Self::ENTIRE, Self::EMPTY);
+            }
+            if b.inf == 0.0 && b.sup == 0.0 {
+                return (Self::EMPTY, Self::EMPTY);
+            }
+            return (-c / b, Self::EMPTY);
+        }
+
+        let d = b.sqr() - FOUR * a * c;
+        if d.sup < 0.0 {
+            return (Self::EMPTY, Self::EMPTY);
+        }
+
+        if a.contains(0.0) {
+            return (Self::ENTIRE, Self::EMPTY);
+        }
+
+        // The negative part of `d` is clipped since it does not yield real roots.
+        let sqrt_d = d.sqrt();
+        let (t1, t2) = if b.inf > 0.0 {
+            let q = -(b + sqrt_d) / TWO;
+            (q / a, c / q)
+        } else if b.sup < 0.0 {
+            let q = (sqrt_d - b) / TWO;
+            (q / a, c / q)
+        } else {
+            // The sign of b is not determined; fall back to the textbook formula.
+            let two_a = TWO * a;
+            ((-b - sqrt_d) / two_a, (-b + sqrt_d) / two_a)
+        };
+
+        if t2.inf < t1.inf {
+            (t2, t1)
+        } else {
+            (t1, t2)
+        }
+    }
+}
+
+impl Interval {
+    /// Returns an enclosure of the definite integral $∫_a^b f(x) \, dx$ over `domain` $= \[a, b\]$,
+    /// given `f` that returns an enclosure of the range of the integrand over an interval.
+    ///
+    /// `domain` is split into `n` subintervals $x_i$ of equal width by [`Interval::mince`],
+    /// and the result is the sum of $f(x_i) × \wid x_i$.
+    /// The result is rigorous, and gets tighter as `n` increases, roughly in proportion to $1/n$.
+    /// `n` is treated as 1 if it is zero.
+    ///
+    /// The result is $∅$ if `domain` is empty, and $\[-∞, +∞\]$ if `domain` is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// // ∫_0^π sin x dx = 2
+    /// let dom = interval!(0.0, Interval::PI.inf).unwrap();
+    /// let y = Interval::riemann_bound(dom, 100, |x| x.sin());
+    /// assert!(y.contains(2.0));
+    /// ```
+    pub fn riemann_bound<F: Fn(Interval) -> Interval>(domain: Interval, n: usize, f: F) -> Self {
+        if domain.is_empty() {
+            return Self::EMPTY;
+        }
+        if !domain.is_common_interval() {
+            return Self::ENTIRE;
+        }
+
+        domain.mince(n.max(1)).into_iter().fold(Self::zero(), |sum, x| {
+            let (x0, x1) = (Self::with_infsup_raw(x.inf, x.inf), Self::with_infsup_raw(x.sup, x.sup));
+            sum + f(x) * (x1 - x0)
+        })
+    }
+
+    /// Iterates $x_{k+1} = f(x_k) ∩ x_k$ starting from $x_0 = $ `initial` to enclose
+    /// a fixed point of a continuous function $g$, given `f` that returns an enclosure
+    /// of the range of $g$ over an interval.
+    ///
+    /// If $f(x_k) ⊆ x_k$ holds for some bounded $x_k$, $g$ has a fixed point in $x_k$
+    /// by Brouwer's fixed-point theorem, and all the subsequent iterates contain it.
+    /// The theorem does not apply to unbounded iterates, which are never used for verification.
+    /// The iteration stops when it becomes stationary or `max_iters` iterations are done,
+    /// and returns `Some` of the last iterate if the condition above has been verified.
+    /// Otherwise, it returns `None`. In particular, `None` is returned if an iterate becomes empty,
+    /// which proves that $g$ has no fixed point in `initial`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// // x = cos x
+    /// let x = Interval::fixed_point(const_interval!(0.0, 1.0), 100, |x| x.cos()).unwrap();
+    /// assert!(x.contains(0.7390851332151607));
+    /// ```
+    pub fn fixed_point<F: Fn(Interval) -> Interval>(
+        initial: Interval,
+        max_iters: u32,
+        f: F,
+    ) -> Option<Interval> {
+        let mut x = initial;
+        let mut verified = false;
+        for _ in 0..max_iters {
+            let y = f(x);
+            let x_next = y.intersection(x);
+            if x_next.is_empty() {
+                return None;
+            }
+            verified |= x.is_common_interval() && y.subset(x);
+            if x_next == x {
+                break;
+            }
+            x = x_next;
+        }
+
+        if verified {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    /// Performs a step of the interval Newton method on `x`:
+    ///
+    /// $$
+    /// N(x) = \left(m - \frac{f(m)}{f′(x)}\right) ∩ x,
+    /// $$
+    ///
+    /// where $m$ is [`x.mid()`](`Interval::mid`), `fx` is an enclosure of $f(m)$,
+    /// and `dfx` is an enclosure of the range of the derivative $f′$ over `x`.
+    ///
+    /// Every root of $f$ in `x` is contained in the result. Hence, if the result is empty,
+    /// $f$ has no root in `x`.
+    /// If `dfx` contains zero, the quotient is typically $\[-∞, +∞\]$ or half-unbounded,
+    /// since the division does not split its result into two intervals, and the result can be
+    /// `x` itself; bisect `x` in that case to make progress.
+    /// In particular, the result is `x` if both `fx` and `dfx` contain zero.
+    /// The result is $∅$ if `x` is empty.
+    ///
+    /// # Examples
+    ///
+    /// Isolating the root $\sqrt 2$ of $f(x) = x^2 - 2$ in $\[1, 2\]$:
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let f = |x: Interval| x.sqr() - const_interval!(2.0);
+    /// let df = |x: Interval| const_interval!(2.0) * x;
+    /// let mut x = const_interval!(1.0, 2.0);
+    /// for _ in 0..6 {
+    ///     let m = x.mid();
+    ///     x = Interval::newton_step(x, f(interval!(m, m).unwrap()), df(x));
+    ///     assert!(x.contains(std::f64::consts::SQRT_2));
+    /// }
+    /// assert!(x.wid() < 1e-15);
+    /// ```
+    #[must_use]
+    pub fn newton_step(x: Interval, fx: Interval, dfx: Interval) -> Self {
+        if x.is_empty() {
+            return x;
+        }
+
+        // The quotient can be any real number, but the division would return
+        // [0, 0] for [0, 0] / M or ∅ for [0, 0] / [0, 0], dropping the roots.
+        if fx.contains(0.0) && dfx.contains(0.0) {
+            return x;
+        }
+
+        let m = x.mid();
+        let m = Self::with_infsup_raw(m, m);
+        (m - fx / dfx).intersection(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn quadratic_roots() {
+        // t^2 - 3t + 2 = (t - 1)(t - 2)
+        let (t1, t2) = I::quadratic_roots(const_interval!(1.0), const_interval!(-3.0), const_interval!(2.0));
+        assert!(t1.contains(1.0) && t2.contains(2.0));
+
+        // -t^2 - 3t - 2 = -(t + 1)(t + 2)
+        let (t1, t2) = I::quadratic_roots(const_interval!(-1.0), const_interval!(-3.0), const_interval!(-2.0));
+        assert!(t1.contains(-2.0) && t2.contains(-1.0));
+
+        // 2t^2 + 4t - 6 = 2(t - 1)(t + 3)
+        let (t1, t2) = I::quadratic_roots(const_interval!(2.0), const_interval!(4.0), const_interval!(-6.0));
+        assert!(t1.contains(-3.0) && t2.contains(1.0));
+
+        // t^2 - 2 with b straddling zero.
+        let (t1, t2) = I::quadratic_roots(const_interval!(1.0), const_interval!(-0.5, 0.5), const_interval!(-2.0));
+        assert!(t1.contains(-std::f64::consts::SQRT_2) && t2.contains(std::f64::consts::SQRT_2));
+
+        // t^2 + 1
+        let (t1, t2) = I::quadratic_roots(const_interval!(1.0), const_interval!(0.0), const_interval!(1.0));
+        assert!(t1.is_empty() && t2.is_empty());
+
+        assert_eq!(I::quadratic_roots(I::EMPTY, I::PI, I::PI), (I::EMPTY, I::EMPTY));
+    }
+
+    #[test]
+    fn quadratic_roots_discriminant_straddling_zero() {
+        // t^2 - 2t + c, c ∈ [0.9, 1.1]; d = [-0.4, 0.4].
+        let (t1, t2) = I::quadratic_roots(const_interval!(1.0), const_interval!(-2.0), const_interval!(0.9, 1.1));
+        assert!(t1.contains(1.0) && t2.contains(1.0));
+        let r = 0.1_f64.sqrt();
+        assert!(t1.contains(1.0 - r * 0.999) && t2.contains(1.0 + r * 0.999));
+    }
+
+    #[test]
+    fn quadratic_roots_degenerate() {
+        // 2t - 4
+        let (t1, t2) = I::quadratic_roots(const_interval!(0.0), const_interval!(2.0), const_interval!(-4.0));
+        assert_eq!(t1, const_interval!(2.0));
+        assert!(t2.is_empty());
+
+        let (t1, t2) = I::quadratic_roots(const_interval!(0.0), const_interval!(0.0), const_interval!(1.0));
+        assert!(t1.is_empty() && t2.is_empty());
+
+        // Every t is a root for b = 0 and c = 0.
+        let (t1, t2) = I::quadratic_roots(const_interval!(0.0), const_interval!(-1.0, 1.0), const_interval!(0.0));
+        assert_eq!(t1, I::ENTIRE);
+        assert!(t2.is_empty());
+        let (t1, _) = I::quadratic_roots(const_interval!(0.0), const_interval!(0.0, 1.0), const_interval!(-1.0, 1.0));
+        assert_eq!(t1, I::ENTIRE);
+
+        // b straddles zero but c does not: t = -c / b.
+        let (t1, t2) = I::quadratic_roots(const_interval!(0.0), const_interval!(0.0, 2.0), const_interval!(-4.0));
+        assert_eq!(t1, const_interval!(2.0, f64::INFINITY));
+        assert!(t2.is_empty());
+
+        let (t1, t2) = I::quadratic_roots(const_interval!(-1.0, 1.0), const_interval!(2.0), const_interval!(-4.0));
+        assert_eq!(t1, I::ENTIRE);
+        assert!(t2.is_empty());
+    }
+
+    #[test]
+    fn riemann_bound() {
+        // ∫_0^1 x dx = 1/2
+        let dom = const_interval!(0.0, 1.0);
+        let mut prev = I::ENTIRE;
+        for n in [1, 2, 10, 100, 1000] {
+            let y = I::riemann_bound(dom, n, |x| x);
+            assert!(y.contains(0.5));
+            assert!(y.subset(prev) && y != prev);
+            prev = y;
+        }
+        assert!(prev.sup - prev.inf < 1e-2);
+
+        assert_eq!(I::riemann_bound(dom, 0, |x| x), I::riemann_bound(dom, 1, |x| x));
+        assert_eq!(I::riemann_bound(dom, 1, |x| x), const_interval!(0.0, 1.0));
+
+        // ∫_{-1}^2 x^2 dx = 3
+        let y = I::riemann_bound(const_interval!(-1.0, 2.0), 100, |x| x.powi(2));
+        assert!(y.contains(3.0));
+
+        assert!(I::riemann_bound(I::EMPTY, 10, |x| x).is_empty());
+        assert_eq!(I::riemann_bound(const_interval!(0.0, f64::INFINITY), 10, |x| x), I::ENTIRE);
+    }
+
+    #[test]
+    fn newton_step() {
+        // x^2 - 2
+        let f = |x: I| x.sqr() - const_interval!(2.0);
+        let df = |x: I| const_interval!(2.0) * x;
+        let step = |x: I| {
+            let m = x.mid();
+            I::newton_step(x, f(interval!(m, m).unwrap()), df(x))
+        };
+
+        let mut x = const_interval!(1.0, 2.0);
+        let mut prev_wid = x.wid();
+        for _ in 0..4 {
+            x = step(x);
+            assert!(x.contains(std::f64::consts::SQRT_2));
+            // Quadratic convergence.
+            assert!(x.wid() < prev_wid * prev_wid || x.wid() < 1e-15);
+            prev_wid = x.wid();
+        }
+
+        // No root in [2, 3].
+        assert!(step(const_interval!(2.0, 3.0)).is_empty());
+
+        // The derivative contains zero; the result is not narrowed.
+        let x = const_interval!(-2.0, 2.0);
+        assert_eq!(step(x), x);
+
+        // x^2 - x vanishes at the midpoint 0 of [-1, 1], and its derivative straddles zero.
+        // The root 1 must not be dropped.
+        let x = const_interval!(-1.0, 1.0);
+        let y = I::newton_step(x, const_interval!(0.0), const_interval!(2.0) * x - const_interval!(1.0));
+        assert_eq!(y, x);
+        assert_eq!(I::newton_step(x, const_interval!(0.0), const_interval!(0.0)), x);
+
+        assert!(I::newton_step(I::EMPTY, I::PI, I::PI).is_empty());
+    }
+
+    #[test]
+    fn fixed_point() {
+        // x = cos x
+        let x = I::fixed_point(const_interval!(0.0, 1.0), 200, |x| x.cos()).unwrap();
+        assert!(x.contains(0.7390851332151607));
+        assert!(x.sup - x.inf < 1e-12);
+
+        // x = (x + 2/x) / 2, x ∈ [1, 2]
+        let x = I::fixed_point(const_interval!(1.0, 2.0), 100, |x| {
+            (x + const_interval!(2.0) / x) / const_interval!(2.0)
+        })
+        .unwrap();
+        assert!(x.contains(std::f64::consts::SQRT_2));
+
+        // The identity is stationary from the beginning.
+        assert_eq!(I::fixed_point(const_interval!(1.0, 2.0), 10, |x| x), Some(const_interval!(1.0, 2.0)));
+
+        // x = 2x has no fixed point in [1, 2].
+        assert_eq!(I::fixed_point(const_interval!(1.0, 2.0), 10, |x| const_interval!(2.0) * x), None);
+
+        // The repelling fixed point of x = 2x in [-1, 1] cannot be verified
+        // as the iteration stagnates.
+        assert_eq!(I::fixed_point(const_interval!(-1.0, 1.0), 10, |x| const_interval!(2.0) * x), None);
+
+        // x = x + 1 has no fixed point, although the unbounded iterates contain their images.
+        let f = |x| x + const_interval!(1.0);
+        assert_eq!(I::fixed_point(I::ENTIRE, 10, f), None);
+        assert_eq!(I::fixed_point(const_interval!(0.0, f64::INFINITY), 10, f), None);
+
+        assert_eq!(I::fixed_point(const_interval!(0.0, 1.0), 0, |x| x.cos()), None);
+        assert_eq!(I::fixed_point(I::EMPTY, 10, |x| x), None);
+    }
+}
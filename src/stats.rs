@@ -0,0 +1,234 @@
+use crate::interval::*;
+
+impl Interval {
+    /// Returns an enclosure of the arithmetic mean of the members of `xs`:
+    ///
+    /// $$
+    /// \frac{1}{n} \sum_{i=1}^n x_i.
+    /// $$
+    ///
+    /// The result is $∅$ if `xs` is empty or any element is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let xs = [const_interval!(1.0), const_interval!(2.0), const_interval!(3.0)];
+    /// assert!(Interval::mean(&xs).contains(2.0));
+    /// ```
+    #[must_use]
+    pub fn mean(xs: &[Interval]) -> Self {
+        if xs.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let n = xs.len() as f64;
+        xs.iter().sum::<Self>() / Self::with_infsup_raw(n, n)
+    }
+
+    /// Returns an enclosure of the geometric mean of the members of `xs`:
+    ///
+    /// $$
+    /// \left(\prod_{i=1}^n x_i\right)^{1/n} = \exp\left(\frac{1}{n} \sum_{i=1}^n \ln x_i\right).
+    /// $$
+    ///
+    /// Each element is restricted to the domain $(0, ∞)$ of $\ln$.
+    /// The result is $∅$ if `xs` is empty or any element is empty after the restriction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let xs = [const_interval!(1.0, 2.0), const_interval!(4.0, 8.0)];
+    /// let m = Interval::geometric_mean(&xs);
+    /// assert!(m.contains(2.5) && m.contains(3.5));
+    /// ```
+    #[must_use]
+    pub fn geometric_mean(xs: &[Interval]) -> Self {
+        if xs.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let n = xs.len() as f64;
+        let sum = xs.iter().fold(Self::zero(), |s, x| s + x.ln());
+        (sum / Self::with_infsup_raw(n, n)).exp()
+    }
+
+    /// Returns an enclosure of the harmonic mean of the members of `xs`:
+    ///
+    /// $$
+    /// \frac{n}{\sum_{i=1}^n 1 / x_i}.
+    /// $$
+    ///
+    /// If an element has zero in its interior, its reciprocal is unbounded on both sides,
+    /// and so is the result, which is $\[-∞, +∞\]$.
+    /// The result is $∅$ if `xs` is empty or any element is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// // 2 / (1/2 + 1/6) = 3
+    /// let m = Interval::harmonic_mean(&[const_interval!(2.0), const_interval!(6.0)]);
+    /// assert!(m.contains(3.0));
+    /// ```
+    #[must_use]
+    pub fn harmonic_mean(xs: &[Interval]) -> Self {
+        if xs.is_empty() || xs.iter().any(|x| x.is_empty()) {
+            return Self::EMPTY;
+        }
+
+        let n = xs.len() as f64;
+        let sum = xs.iter().map(|x| x.recip()).sum::<Self>();
+        Self::with_infsup_raw(n, n) / sum
+    }
+
+    /// Returns an enclosure of the weighted arithmetic mean of the members of `xs`:
+    ///
+    /// $$
+    /// \frac{\sum_{i=1}^n w_i x_i}{\sum_{i=1}^n w_i},
+    /// $$
+    ///
+    /// where $w_i$ are the members of `weights`, which are taken as exact.
+    ///
+    /// The result is $∅$ if `xs` is empty or any element is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs` and `weights` have different lengths, or if any weight is not
+    /// positive and finite. Thus the sum of the weights is never zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari_wasm::*;
+    /// let xs = [const_interval!(1.0, 2.0), const_interval!(4.0)];
+    /// let m = Interval::weighted_mean(&xs, &[3.0, 1.0]);
+    /// assert!(m.contains(1.75) && m.contains(2.5));
+    /// ```
+    #[must_use]
+    pub fn weighted_mean(xs: &[Interval], weights: &[f64]) -> Self {
+        assert_eq!(xs.len(), weights.len());
+        assert!(weights.iter().all(|&w| w > 0.0 && w.is_finite()), "the weights must be positive and finite");
+
+        if xs.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let (sum, sum_w) = xs.iter().zip(weights).fold(
+            (Self::zero(), Self::zero()),
+            |(sum, sum_w), (&x, &w)| {
+                let w = Self::with_infsup_raw(w, w);
+                (sum + w * x, sum_w + w)
+            },
+        );
+        sum / sum_w
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+
+    fn approx_eq(x: I, y: I) -> bool {
+        (x.inf - y.inf).abs() <= 1e-12 * y.inf.abs() && (x.sup - y.sup).abs() <= 1e-12 * y.sup.abs()
+    }
+
+    #[test]
+    fn mean() {
+        let m = I::mean(&[const_interval!(1.0), const_interval!(2.0), const_interval!(3.0)]);
+        assert!(m.contains(2.0));
+        assert!(approx_eq(m, const_interval!(2.0)));
+
+        // (0.1 + 0.2 + 0.3) / 3
+        let m = I::mean(&[const_interval!(0.1), const_interval!(0.2), const_interval!(0.3)]);
+        assert!(approx_eq(m, const_interval!(0.2)));
+
+        let m = I::mean(&[const_interval!(-1.0, 1.0), const_interval!(2.0, 4.0)]);
+        assert!(approx_eq(m, const_interval!(0.5, 2.5)));
+
+        assert!(I::mean(&[]).is_empty());
+        assert!(I::mean(&[const_interval!(1.0), I::EMPTY]).is_empty());
+    }
+
+    #[test]
+    fn geometric_mean() {
+        // (2 × 8)^(1/2) = 4
+        let m = I::geometric_mean(&[const_interval!(2.0), const_interval!(8.0)]);
+        assert!(approx_eq(m, const_interval!(4.0)));
+
+        // (1 × 3 × 9)^(1/3) = 3
+        let m = I::geometric_mean(&[const_interval!(1.0), const_interval!(3.0), const_interval!(9.0)]);
+        assert!(approx_eq(m, const_interval!(3.0)));
+
+        let m = I::geometric_mean(&[const_interval!(1.0, 2.0), const_interval!(4.0, 8.0)]);
+        assert!(approx_eq(m, const_interval!(2.0, 4.0)));
+
+        // Clipped to (0, ∞).
+        let m = I::geometric_mean(&[const_interval!(-1.0, 4.0), const_interval!(4.0)]);
+        assert_eq!(m.inf, 0.0);
+        assert!(approx_eq(m, const_interval!(0.0, 4.0)));
+
+        assert!(I::geometric_mean(&[]).is_empty());
+        assert!(I::geometric_mean(&[const_interval!(1.0), I::EMPTY]).is_empty());
+        assert!(I::geometric_mean(&[const_interval!(1.0), const_interval!(-2.0, 0.0)]).is_empty());
+    }
+
+    #[test]
+    fn harmonic_mean() {
+        // 3 / (1 + 1/2 + 1/4) = 12/7
+        let m = I::harmonic_mean(&[const_interval!(1.0), const_interval!(2.0), const_interval!(4.0)]);
+        assert!(m.contains(12.0 / 7.0));
+        assert!(approx_eq(m, const_interval!(12.0 / 7.0)));
+
+        // 2 / (1/2 + 1/6) = 3
+        let m = I::harmonic_mean(&[const_interval!(2.0), const_interval!(6.0)]);
+        assert!(approx_eq(m, const_interval!(3.0)));
+
+        let m = I::harmonic_mean(&[const_interval!(1.0, 2.0), const_interval!(1.0, 2.0)]);
+        assert!(approx_eq(m, const_interval!(1.0, 2.0)));
+
+        assert_eq!(I::harmonic_mean(&[const_interval!(1.0), const_interval!(-1.0, 2.0)]), I::ENTIRE);
+
+        assert!(I::harmonic_mean(&[]).is_empty());
+        assert!(I::harmonic_mean(&[const_interval!(1.0), I::EMPTY]).is_empty());
+    }
+
+    #[test]
+    fn weighted_mean() {
+        let xs = [const_interval!(1.0), const_interval!(2.0, 3.0), const_interval!(-1.0, 6.0)];
+
+        // Equal weights: (1 + [2, 3] + [-1, 6]) / 3 = [2/3, 10/3]
+        for w in [1.0, 0.5, 7.0] {
+            let m = I::weighted_mean(&xs, &[w, w, w]);
+            assert!(m.contains(2.0 / 3.0) && m.contains(10.0 / 3.0));
+            assert!(approx_eq(m, interval!(2.0 / 3.0, 10.0 / 3.0).unwrap()));
+        }
+
+        // (3 × 1 + 1 × [2, 3]) / 4 = [5/4, 6/4]
+        let m = I::weighted_mean(&xs[..2], &[3.0, 1.0]);
+        assert!(approx_eq(m, const_interval!(1.25, 1.5)));
+
+        assert!(I::weighted_mean(&[], &[]).is_empty());
+        assert!(I::weighted_mean(&[const_interval!(1.0), I::EMPTY], &[1.0, 1.0]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_mean_length_mismatch() {
+        let _ = I::weighted_mean(&[const_interval!(1.0)], &[1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_mean_zero_weight() {
+        let _ = I::weighted_mean(&[const_interval!(1.0), const_interval!(2.0)], &[1.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_mean_nan_weight() {
+        let _ = I::weighted_mean(&[const_interval!(1.0)], &[f64::NAN]);
+    }
+}
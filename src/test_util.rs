@@ -0,0 +1,13 @@
+// Helpers shared by the tests of the modules.
+
+// Returns `n` pseudorandom numbers uniformly distributed in [0, 1),
+// generated by xorshift64 from the nonzero `seed`, so that the tests are reproducible.
+pub(crate) fn sample_f64s(seed: u64, n: usize) -> impl Iterator<Item = f64> {
+    let mut state = seed;
+    (0..n).map(move |_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    })
+}
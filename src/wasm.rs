@@ -0,0 +1,134 @@
+use crate::interval::*;
+use wasm_bindgen::prelude::*;
+
+/// A wrapper of [`Interval`] exported to JavaScript as `Interval`.
+///
+/// Every method returns a new object, and the wrapped interval is never modified.
+///
+/// Requires the `wasm` feature.
+#[wasm_bindgen(js_name = Interval)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WasmInterval(Interval);
+
+#[wasm_bindgen(js_class = Interval)]
+impl WasmInterval {
+    /// Creates an interval $\[a, b\]$.
+    ///
+    /// Instead of throwing an exception, the result is empty if the bounds are invalid,
+    /// as with [`Interval::from_bounds_lossy`].
+    #[wasm_bindgen(constructor)]
+    pub fn new(a: f64, b: f64) -> Self {
+        Self(Interval::from_bounds_lossy(a, b))
+    }
+
+    /// Returns $∅$.
+    pub fn empty() -> Self {
+        Self(Interval::EMPTY)
+    }
+
+    /// Returns $\[-∞, +∞\]$.
+    pub fn entire() -> Self {
+        Self(Interval::ENTIRE)
+    }
+
+    /// Returns the lower bound, or NaN if the interval is empty.
+    #[wasm_bindgen(getter)]
+    pub fn inf(&self) -> f64 {
+        self.0.inf_raw()
+    }
+
+    /// Returns the upper bound, or NaN if the interval is empty.
+    #[wasm_bindgen(getter)]
+    pub fn sup(&self) -> f64 {
+        self.0.sup_raw()
+    }
+
+    /// Returns `true` if the interval is empty.
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn add(&self, rhs: &WasmInterval) -> Self {
+        Self(self.0 + rhs.0)
+    }
+
+    pub fn sub(&self, rhs: &WasmInterval) -> Self {
+        Self(self.0 - rhs.0)
+    }
+
+    pub fn mul(&self, rhs: &WasmInterval) -> Self {
+        Self(self.0 * rhs.0)
+    }
+
+    pub fn div(&self, rhs: &WasmInterval) -> Self {
+        Self(self.0 / rhs.0)
+    }
+
+    pub fn sin(&self) -> Self {
+        Self(self.0.sin())
+    }
+
+    pub fn cos(&self) -> Self {
+        Self(self.0.cos())
+    }
+
+    pub fn sqrt(&self) -> Self {
+        Self(self.0.sqrt())
+    }
+
+    /// Returns the `n`th power of the interval. See [`Interval::powi`].
+    pub fn pow_i(&self, n: i32) -> Self {
+        Self(self.0.powi(n))
+    }
+
+    /// Returns the string representation, such as `"[1, 2]"` or `"[empty]"`.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<Interval> for WasmInterval {
+    fn from(x: Interval) -> Self {
+        Self(x)
+    }
+}
+
+impl From<WasmInterval> for Interval {
+    fn from(x: WasmInterval) -> Self {
+        x.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use WasmInterval as W;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn ops() {
+        let x = W::new(1.0, 2.0);
+        let y = W::new(3.0, 4.0);
+        assert_eq!(x.add(&y), W::new(4.0, 6.0));
+        assert_eq!((x.add(&y).inf(), x.add(&y).sup()), (4.0, 6.0));
+        assert_eq!(y.sub(&x), W::new(1.0, 3.0));
+        assert_eq!(x.mul(&y), W::new(3.0, 8.0));
+        assert_eq!(W::new(4.0, 9.0).sqrt(), W::new(2.0, 3.0));
+        assert_eq!(x.pow_i(2), W::new(1.0, 4.0));
+        assert_eq!(Interval::from(x.sin()), const_interval!(1.0, 2.0).sin());
+        assert_eq!(Interval::from(x.cos()), const_interval!(1.0, 2.0).cos());
+        assert_eq!(Interval::from(x.div(&y)), const_interval!(1.0, 2.0) / const_interval!(3.0, 4.0));
+
+        assert!(W::empty().is_empty());
+        assert!(W::empty().inf().is_nan());
+        assert!(W::new(2.0, 1.0).is_empty());
+        assert_eq!(W::entire().inf(), f64::NEG_INFINITY);
+        assert_eq!(W::entire().to_string(), "[entire]");
+    }
+}